@@ -1,15 +1,31 @@
 use crate::{
     error::ClientError,
+    governor::RateLimitGovernor,
+    retry::RetryPolicy,
     traits::{Client, RegisteredClient},
 };
 use imgurs_model::model::authorization::{AccessToken, ClientID, ClientSecret, RefreshToken};
 use reqwest::{
-    header::{HeaderMap, HeaderName, HeaderValue},
-    Client as ReqwestClient, ClientBuilder as ReqwestClientBuilder,
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
+    Client as ReqwestClient, ClientBuilder as ReqwestClientBuilder, Proxy,
 };
 use serde::{Deserialize, Serialize};
-use std::{error::Error, fmt, str::FromStr};
+use std::{fmt, str::FromStr, time::Duration};
 use time::{serde::timestamp, OffsetDateTime};
+use url::Url;
+
+/// Default base URL of the API endpoints
+pub const DEFAULT_API_URL: &str = "https://api.imgur.com/";
+/// Default base URL of the OAuth endpoints
+pub const DEFAULT_OAUTH_URL: &str = "https://api.imgur.com/oauth2/";
+
+fn default_api_url() -> Url {
+    Url::parse(DEFAULT_API_URL).expect("Default API URL is valid")
+}
+
+fn default_oauth_url() -> Url {
+    Url::parse(DEFAULT_OAUTH_URL).expect("Default OAuth URL is valid")
+}
 
 /// Client basic settings
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -18,6 +34,12 @@ pub struct ClientSettings {
     pub client_id: ClientID,
     /// Client secret token
     pub client_secret: ClientSecret,
+    /// Base URL of the API endpoints
+    #[serde(default = "default_api_url")]
+    pub api_url: Url,
+    /// Base URL of the OAuth endpoints
+    #[serde(default = "default_oauth_url")]
+    pub oauth_url: Url,
 }
 
 /// Client API authentication related settings
@@ -39,31 +61,223 @@ pub struct AuthenticationSettings {
 pub struct BasicClient {
     client: ReqwestClient,
     settings: ClientSettings,
+    governor: Option<RateLimitGovernor>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+/// TLS backend of the internal client, selectable when both TLS cargo features are enabled
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TlsBackend {
+    #[cfg(feature = "rustls-tls")]
+    Rustls,
+    #[cfg(feature = "native-tls")]
+    Native,
+}
+
+/// Client builder
+///
+/// Configures the transport of a `BasicClient`, either building the internal client or using a custom one.
+#[derive(Debug)]
+pub struct ClientBuilder {
+    settings: ClientSettings,
+    client: Option<ReqwestClient>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    user_agent: Option<String>,
+    default_headers: Vec<(String, String)>,
+    tls_backend: Option<TlsBackend>,
+    governor: Option<RateLimitGovernor>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl ClientBuilder {
+    /// `ClientBuilder` constructor
+    pub fn new(client_id: ClientID, client_secret: ClientSecret) -> Self {
+        ClientBuilder {
+            settings: ClientSettings {
+                client_id,
+                client_secret,
+                api_url: default_api_url(),
+                oauth_url: default_oauth_url(),
+            },
+            client: None,
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            user_agent: None,
+            default_headers: Vec::new(),
+            tls_backend: None,
+            governor: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Use a custom internal client
+    ///
+    /// The timeouts, proxy, user agent, default headers and TLS backend are ignored,
+    /// as they are part of the custom client configuration.
+    pub fn reqwest_client(mut self, client: ReqwestClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Timeout of each request, from the connection to the end of the response body
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout of the connection phase of each request
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send the requests through a proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// `User-Agent` header of the requests
+    pub fn user_agent<T: Into<String>>(mut self, user_agent: T) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Additional header sent with every request, validated on build
+    ///
+    /// The `Authorization` header carries the client credentials and is rejected.
+    pub fn default_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Use rustls as TLS backend
+    #[cfg(feature = "rustls-tls")]
+    pub fn use_rustls_tls(mut self) -> Self {
+        self.tls_backend = Some(TlsBackend::Rustls);
+        self
+    }
+
+    /// Use the native TLS backend of the platform
+    #[cfg(feature = "native-tls")]
+    pub fn use_native_tls(mut self) -> Self {
+        self.tls_backend = Some(TlsBackend::Native);
+        self
+    }
+
+    /// Base URL of the API endpoints
+    pub fn api_url(mut self, api_url: Url) -> Self {
+        self.settings.api_url = api_url;
+        self
+    }
+
+    /// Base URL of the OAuth endpoints
+    pub fn oauth_url(mut self, oauth_url: Url) -> Self {
+        self.settings.oauth_url = oauth_url;
+        self
+    }
+
+    /// Enable the rate limit governor
+    pub fn governor(mut self, governor: RateLimitGovernor) -> Self {
+        self.governor = Some(governor);
+        self
+    }
+
+    /// Enable the retry of the failed requests, failed requests are not retried by default
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Build the `BasicClient`
+    pub fn build(self) -> Result<BasicClient, ClientError> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    HeaderName::from_str("Authorization")?,
+                    HeaderValue::from_str(&format!("Client-ID {}", self.settings.client_id.0))?,
+                );
+                for (name, value) in &self.default_headers {
+                    let name = HeaderName::from_str(name)?;
+                    if name == AUTHORIZATION {
+                        return Err(ClientError::ReservedHeader(name));
+                    }
+                    headers.insert(name, HeaderValue::from_str(value)?);
+                }
+
+                let mut builder = ReqwestClientBuilder::new().default_headers(headers);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                match self.tls_backend {
+                    #[cfg(feature = "rustls-tls")]
+                    Some(TlsBackend::Rustls) => builder = builder.use_rustls_tls(),
+                    #[cfg(feature = "native-tls")]
+                    Some(TlsBackend::Native) => builder = builder.use_native_tls(),
+                    None => {}
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(BasicClient {
+            client,
+            settings: self.settings,
+            governor: self.governor,
+            retry_policy: self.retry_policy,
+        })
+    }
 }
 
 impl BasicClient {
     /// `Client` constructor
-    pub fn new(client_id: ClientID, client_secret: ClientSecret) -> Result<Self, Box<dyn Error>> {
-        let client = ReqwestClientBuilder::new()
-            .default_headers(
-                vec![(
-                    HeaderName::from_str("Authorization")?,
-                    HeaderValue::from_str(&format!("Client-ID {}", client_id.0))?,
-                )]
-                .iter()
-                .cloned()
-                .collect(),
-            )
-            .build()?;
+    ///
+    /// Without a rate limit governor nor a retry policy, see `BasicClient::builder` to enable them.
+    pub fn new(client_id: ClientID, client_secret: ClientSecret) -> Result<Self, ClientError> {
+        ClientBuilder::new(client_id, client_secret).build()
+    }
 
-        // TODO: input checks
+    /// `ClientBuilder` constructor, to customize the client
+    pub fn builder(client_id: ClientID, client_secret: ClientSecret) -> ClientBuilder {
+        ClientBuilder::new(client_id, client_secret)
+    }
 
-        let settings = ClientSettings {
-            client_id,
-            client_secret,
-        };
+    /// Enable the rate limit governor, holding back requests once the credits run low
+    pub fn with_governor(mut self, governor: RateLimitGovernor) -> Self {
+        self.governor = Some(governor);
+        self
+    }
+
+    /// Enable the retry of the failed requests
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
 
-        Ok(BasicClient { client, settings })
+    /// Send the API requests to a different base URL, such as a local mock server
+    pub fn with_api_url(mut self, api_url: Url) -> Self {
+        self.settings.api_url = api_url;
+        self
+    }
+
+    /// Send the OAuth requests to a different base URL, such as a local mock server
+    pub fn with_oauth_url(mut self, oauth_url: Url) -> Self {
+        self.settings.oauth_url = oauth_url;
+        self
     }
 
     /// `AuthenticatedClient` constructor from a `Client`
@@ -72,7 +286,7 @@ impl BasicClient {
         access_token: AccessToken,
         refresh_token: RefreshToken,
         expires_in: C,
-    ) -> Result<AuthenticatedClient, Box<dyn Error>>
+    ) -> Result<AuthenticatedClient, ClientError>
     where
         C: Into<OffsetDateTime>,
     {
@@ -88,6 +302,8 @@ impl BasicClient {
             client: self.client,
             client_settings: self.settings,
             authentication_settings: authentication,
+            governor: self.governor,
+            retry_policy: self.retry_policy,
         })
     }
 }
@@ -116,6 +332,14 @@ impl Client for BasicClient {
     fn get_settings(&self) -> &ClientSettings {
         &self.settings
     }
+
+    fn get_governor(&self) -> Option<&RateLimitGovernor> {
+        self.governor.as_ref()
+    }
+
+    fn get_retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
 }
 
 /// Authenticated client
@@ -126,6 +350,22 @@ pub struct AuthenticatedClient {
     client: ReqwestClient,
     client_settings: ClientSettings,
     authentication_settings: AuthenticationSettings,
+    governor: Option<RateLimitGovernor>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl AuthenticatedClient {
+    /// Enable the rate limit governor, holding back requests once the credits run low
+    pub fn with_governor(mut self, governor: RateLimitGovernor) -> Self {
+        self.governor = Some(governor);
+        self
+    }
+
+    /// Enable the retry of the failed requests
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
 }
 
 impl Client for AuthenticatedClient {
@@ -155,6 +395,18 @@ impl Client for AuthenticatedClient {
     fn get_settings(&self) -> &ClientSettings {
         &self.client_settings
     }
+
+    fn get_governor(&self) -> Option<&RateLimitGovernor> {
+        self.governor.as_ref()
+    }
+
+    fn get_retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
 }
 
 impl RegisteredClient for AuthenticatedClient {
@@ -207,6 +459,203 @@ impl fmt::Display for SortPreference {
     }
 }
 
+/// Gallery section
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Section {
+    /// Most viral content
+    #[default]
+    Hot,
+    /// Top rated content
+    Top,
+    /// User submitted content
+    User,
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Section::Hot => "hot",
+                Section::Top => "top",
+                Section::User => "user",
+            }
+        )
+    }
+}
+
+/// Gallery contents sorting preference
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GallerySort {
+    /// Most viral first
+    #[default]
+    Viral,
+    /// Top rated first
+    Top,
+    /// Newest first
+    Time,
+    /// Rising first, only available with the `User` section
+    Rising,
+}
+
+impl fmt::Display for GallerySort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                GallerySort::Viral => "viral",
+                GallerySort::Top => "top",
+                GallerySort::Time => "time",
+                GallerySort::Rising => "rising",
+            }
+        )
+    }
+}
+
+/// Gallery search results sorting preference
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSort {
+    /// Newest first
+    #[default]
+    Time,
+    /// Most viral first
+    Viral,
+    /// Top rated first
+    Top,
+}
+
+impl fmt::Display for SearchSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SearchSort::Time => "time",
+                SearchSort::Viral => "viral",
+                SearchSort::Top => "top",
+            }
+        )
+    }
+}
+
+/// Tag and topic galleries sorting preference
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagSort {
+    /// Most viral first
+    #[default]
+    Viral,
+    /// Top rated first
+    Top,
+    /// Newest first
+    Time,
+}
+
+impl fmt::Display for TagSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TagSort::Viral => "viral",
+                TagSort::Top => "top",
+                TagSort::Time => "time",
+            }
+        )
+    }
+}
+
+/// Subreddit gallery sorting preference
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubredditSort {
+    /// Newest first
+    #[default]
+    Time,
+    /// Top rated first
+    Top,
+}
+
+impl fmt::Display for SubredditSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SubredditSort::Time => "time",
+                SubredditSort::Top => "top",
+            }
+        )
+    }
+}
+
+/// Gallery comments sorting preference
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentSort {
+    /// Best rated first
+    #[default]
+    Best,
+    /// Top rated first
+    Top,
+    /// Newest first
+    New,
+}
+
+impl fmt::Display for CommentSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CommentSort::Best => "best",
+                CommentSort::Top => "top",
+                CommentSort::New => "new",
+            }
+        )
+    }
+}
+
+/// Time window of the gallery contents
+///
+/// Only used when sorting or selecting top content.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Window {
+    /// Last day
+    #[default]
+    Day,
+    /// Last week
+    Week,
+    /// Last month
+    Month,
+    /// Last year
+    Year,
+    /// All time
+    All,
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Window::Day => "day",
+                Window::Week => "week",
+                Window::Month => "month",
+                Window::Year => "year",
+                Window::All => "all",
+            }
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use imgurs_model::{
@@ -223,7 +672,64 @@ mod tests {
     use reqwest::StatusCode;
     use std::{convert::TryFrom, env, error::Error};
 
-    use crate::{client::BasicClient, traits::Client};
+    use crate::{
+        client::{BasicClient, ClientBuilder},
+        error::ClientError,
+        retry::RetryPolicy,
+        traits::Client,
+    };
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn test_client_builder() -> Result<(), Box<dyn Error>> {
+        let client = ClientBuilder::new(ClientID("id".into()), ClientSecret("secret".into()))
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5))
+            .user_agent("imgurs-test")
+            .default_header("X-Test", "value")
+            .api_url(Url::parse("http://localhost:8080/")?)
+            .build()?;
+
+        assert_eq!(client.get_settings().client_id.0, "id");
+        assert_eq!(
+            client.get_settings().api_url.as_str(),
+            "http://localhost:8080/"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_client_retries_are_opt_in() -> Result<(), Box<dyn Error>> {
+        let client = BasicClient::new(ClientID("id".into()), ClientSecret("secret".into()))?;
+        assert_eq!(client.get_retry_policy(), None);
+
+        let client = BasicClient::builder(ClientID("id".into()), ClientSecret("secret".into()))
+            .retry_policy(RetryPolicy::default())
+            .build()?;
+        assert_eq!(client.get_retry_policy(), Some(&RetryPolicy::default()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_client_builder_invalid_header() {
+        let res = BasicClient::builder(ClientID("id".into()), ClientSecret("secret".into()))
+            .default_header("Invalid header", "value")
+            .build();
+
+        assert!(matches!(res, Err(ClientError::InvalidHeaderName(_))));
+    }
+
+    #[test]
+    fn test_client_builder_rejects_authorization_header() {
+        let res = BasicClient::builder(ClientID("id".into()), ClientSecret("secret".into()))
+            .default_header("authorization", "Client-ID other")
+            .build();
+
+        assert!(matches!(res, Err(ClientError::ReservedHeader(_))));
+    }
 
     #[tokio::test]
     async fn test_deserialize_account_remote() -> Result<(), Box<dyn Error>> {
@@ -0,0 +1,401 @@
+//! API endpoint specification
+//!
+//! An `Endpoint` describes a single API call: its method, path, parameters, body, response type
+//! and whether it requires an authenticated user. `Client::execute` sends it and parses the response.
+use std::marker::PhantomData;
+
+use reqwest::{multipart::Form, Method, Response as ReqwestResponse, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::debug;
+
+use imgurs_model::model::basic::{Basic, Data};
+
+use crate::{
+    error::{ClientError, InvalidResponseContext},
+    response::{RateLimit, Response},
+};
+
+/// Base URL an endpoint path is relative to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Base {
+    /// API base URL
+    Api,
+    /// OAuth base URL
+    OAuth,
+}
+
+/// Shape of the body of an endpoint response
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Envelope {
+    /// Content wrapped in the basic response model, with `data`, `success` and `status`
+    Basic,
+    /// Content wrapped in a `data` field only
+    Data,
+    /// Bare content, errors are still wrapped in the basic response model
+    Bare,
+}
+
+/// Body of an endpoint request
+#[derive(Debug)]
+pub enum Body {
+    /// No body
+    Empty,
+    /// URL encoded form
+    Form(Vec<(String, String)>),
+    /// Multipart form
+    Multipart(Form),
+}
+
+/// API endpoint, returning a `T`
+#[derive(Debug)]
+pub struct Endpoint<T> {
+    method: Method,
+    base: Base,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Body,
+    envelope: Envelope,
+    requires_auth: bool,
+    response: PhantomData<fn() -> T>,
+}
+
+impl<T> Endpoint<T> {
+    /// `Endpoint` constructor, with a path relative to the API base URL
+    pub fn new<P: Into<String>>(method: Method, path: P) -> Self {
+        Endpoint {
+            method,
+            base: Base::Api,
+            path: path.into(),
+            query: Vec::new(),
+            body: Body::Empty,
+            envelope: Envelope::Basic,
+            requires_auth: false,
+            response: PhantomData,
+        }
+    }
+
+    /// GET endpoint
+    pub fn get<P: Into<String>>(path: P) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    /// POST endpoint
+    pub fn post<P: Into<String>>(path: P) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    /// PUT endpoint
+    pub fn put<P: Into<String>>(path: P) -> Self {
+        Self::new(Method::PUT, path)
+    }
+
+    /// DELETE endpoint
+    pub fn delete<P: Into<String>>(path: P) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    /// Make the path relative to the OAuth base URL
+    pub fn oauth(mut self) -> Self {
+        self.base = Base::OAuth;
+        self
+    }
+
+    /// Add a query parameter
+    pub fn query<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add multiple query parameters
+    pub fn query_pairs<K, V, I>(mut self, params: I) -> Self
+    where
+        K: ToString,
+        V: ToString,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.query.extend(
+            params
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string())),
+        );
+        self
+    }
+
+    /// Send a URL encoded form body
+    pub fn form<K, V, I>(mut self, params: I) -> Self
+    where
+        K: ToString,
+        V: ToString,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.body = Body::Form(
+            params
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Send a multipart form body
+    pub fn multipart(mut self, form: Form) -> Self {
+        self.body = Body::Multipart(form);
+        self
+    }
+
+    /// Set the shape of the response body
+    pub fn envelope(mut self, envelope: Envelope) -> Self {
+        self.envelope = envelope;
+        self
+    }
+
+    /// Require an authenticated user
+    pub fn authenticated(mut self) -> Self {
+        self.requires_auth = true;
+        self
+    }
+
+    /// HTTP method of the endpoint
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Base URL the path is relative to
+    pub fn base(&self) -> Base {
+        self.base
+    }
+
+    /// Path of the endpoint
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Query parameters of the endpoint
+    pub fn query_parameters(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    /// Whether the endpoint requires an authenticated user
+    pub fn requires_auth(&self) -> bool {
+        self.requires_auth
+    }
+
+    /// Split the endpoint into the request body and the response envelope
+    pub(crate) fn into_parts(self) -> (Body, Envelope) {
+        (self.body, self.envelope)
+    }
+}
+
+/// Number of characters of an unparsable response body kept in the error
+const BODY_SNIPPET_LENGTH: usize = 256;
+
+/// Content wrapped in a `data` field only
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DataWrapper<T> {
+    data: T,
+}
+
+/// Success flag of the basic response model, ignoring the other fields
+#[derive(Deserialize)]
+struct SuccessFlag {
+    success: bool,
+}
+
+/// Parse the body of a response according to its envelope
+pub(crate) async fn parse_response<T: DeserializeOwned>(
+    res: ReqwestResponse,
+    method: &Method,
+    base: Base,
+    envelope: Envelope,
+) -> Result<Response<T>, ClientError> {
+    let status = res.status();
+    let request = res.url().path().to_string();
+    let headers = res.headers().clone();
+
+    let text = res.text().await?;
+
+    // The body is never logged, as the OAuth responses contain the user tokens
+    debug!("Response: {} ({} bytes)", status, text.len());
+
+    let content =
+        parse_body(status, &text, envelope).map_err(|source| ClientError::InvalidResponse {
+            context: Box::new(InvalidResponseContext {
+                status: status.as_u16(),
+                request,
+                method: method.clone(),
+                rate_limit: RateLimit::from_headers(&headers),
+                body_snippet: body_snippet(status, base, &text),
+            }),
+            source,
+        })?;
+
+    Ok(Response { content, headers })
+}
+
+/// Beginning of an unparsable response body, only for API error statuses
+fn body_snippet(status: StatusCode, base: Base, text: &str) -> Option<String> {
+    if base == Base::OAuth || status.is_success() {
+        return None;
+    }
+    Some(text.chars().take(BODY_SNIPPET_LENGTH).collect())
+}
+
+/// Whether a response body is an error in the basic response model
+fn is_error_envelope(status: StatusCode, text: &str) -> bool {
+    !status.is_success()
+        || serde_json::from_str::<SuccessFlag>(text).is_ok_and(|flag| !flag.success)
+}
+
+/// Parse a response body, wrapping unwrapped content in the basic response model
+///
+/// Unwrapped envelopes fall back to the basic response model only for error responses,
+/// otherwise the content parsing error is returned.
+fn parse_body<T: DeserializeOwned>(
+    status: StatusCode,
+    text: &str,
+    envelope: Envelope,
+) -> Result<Basic<T>, serde_json::Error> {
+    let unwrapped = match envelope {
+        Envelope::Basic => return serde_json::from_str(text),
+        Envelope::Data => serde_json::from_str::<DataWrapper<T>>(text).map(|wrapper| wrapper.data),
+        Envelope::Bare => serde_json::from_str::<T>(text),
+    };
+    match unwrapped {
+        Ok(data) => Ok(Basic {
+            data: Data::Content(data),
+            success: status.is_success(),
+            status: status.as_u16(),
+        }),
+        Err(_) if is_error_envelope(status, text) => serde_json::from_str(text),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        endpoint::{body_snippet, parse_body, Base, Endpoint, Envelope},
+        error::{ClientError, InvalidResponseContext},
+        response::RateLimit,
+    };
+    use imgurs_model::model::basic::{Basic, Data};
+    use reqwest::{Method, StatusCode};
+    use serde::Deserialize;
+    use std::error::Error;
+
+    #[test]
+    fn test_endpoint_builder() {
+        let endpoint = Endpoint::<bool>::post("token")
+            .oauth()
+            .query("page", 2)
+            .query_pairs([("sort", "newest")])
+            .authenticated();
+
+        assert_eq!(endpoint.method(), &Method::POST);
+        assert_eq!(endpoint.base(), Base::OAuth);
+        assert_eq!(endpoint.path(), "token");
+        assert_eq!(
+            endpoint.query_parameters(),
+            &[
+                ("page".to_owned(), "2".to_owned()),
+                ("sort".to_owned(), "newest".to_owned())
+            ]
+        );
+        assert!(endpoint.requires_auth());
+        assert!(!Endpoint::<bool>::get("3/credits").requires_auth());
+    }
+
+    #[test]
+    fn test_parse_body_envelopes() -> Result<(), Box<dyn Error>> {
+        let basic: Basic<bool> = parse_body(
+            StatusCode::OK,
+            r#"{"data":true,"success":true,"status":200}"#,
+            Envelope::Basic,
+        )?;
+        assert_eq!(basic.data, Data::Content(true));
+
+        let data: Basic<bool> = parse_body(StatusCode::OK, r#"{"data":true}"#, Envelope::Data)?;
+        assert_eq!(data.data, Data::Content(true));
+        assert_eq!(data.status, 200);
+
+        let bare: Basic<u64> = parse_body(StatusCode::CREATED, "42", Envelope::Bare)?;
+        assert_eq!(bare.data, Data::Content(42));
+        assert_eq!(bare.status, 201);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_body_error() -> Result<(), Box<dyn Error>> {
+        let error: Basic<u64> = parse_body(
+            StatusCode::BAD_REQUEST,
+            r#"{"data":{"error":"Invalid grant_type parameter or parameter missing","request":"/oauth2/token","method":"POST"},"success":false,"status":400}"#,
+            Envelope::Bare,
+        )?;
+        assert!(error.result().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_body_content_error() {
+        #[derive(Debug, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Token {
+            #[allow(dead_code)]
+            access_token: String,
+        }
+
+        let error = parse_body::<Token>(
+            StatusCode::OK,
+            r#"{"access_token":"abc","new_field":1}"#,
+            Envelope::Bare,
+        )
+        .expect_err("Unknown fields are rejected");
+        assert!(error.to_string().contains("new_field"));
+    }
+
+    #[test]
+    fn test_parse_body_invalid() {
+        let body = format!("<html>{}</html>", "Imgur is over capacity! ".repeat(20));
+        assert!(
+            parse_body::<bool>(StatusCode::SERVICE_UNAVAILABLE, &body, Envelope::Basic).is_err()
+        );
+
+        let snippet = body_snippet(StatusCode::SERVICE_UNAVAILABLE, Base::Api, &body)
+            .expect("API errors keep a snippet");
+        assert!(body.starts_with(&snippet));
+        assert_eq!(snippet.chars().count(), 256);
+
+        assert_eq!(body_snippet(StatusCode::OK, Base::Api, &body), None);
+        assert_eq!(
+            body_snippet(StatusCode::BAD_REQUEST, Base::OAuth, &body),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_response_error() {
+        let body = r#"{"access_token":"secret","expires_in":"never"}"#;
+        let source = parse_body::<u64>(StatusCode::OK, body, Envelope::Bare)
+            .expect_err("The body is not a number");
+        let error = ClientError::InvalidResponse {
+            context: Box::new(InvalidResponseContext {
+                status: 200,
+                request: "/oauth2/token".to_string(),
+                method: Method::POST,
+                rate_limit: RateLimit::default(),
+                body_snippet: body_snippet(StatusCode::OK, Base::OAuth, body),
+            }),
+            source,
+        };
+
+        assert_eq!(error.status(), Some(200));
+        assert_eq!(
+            error.to_string(),
+            "Invalid response: 200 POST /oauth2/token"
+        );
+        assert!(error.source().is_some());
+    }
+}
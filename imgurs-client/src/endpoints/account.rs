@@ -1,22 +1,161 @@
 //! Account API implementation
 
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
-
 use imgurs_model::model::{
-    account::{Account, AccountBlocks, BlockedStatus},
-    basic::{Basic, Data},
-    common::Username,
-    custom_gallery::CustomGalleryItem,
+    account::{
+        Account, AccountAvatar, AccountBlocks, AvailableAvatars, AvailableCovers, BlockedStatus,
+        UserFollow,
+    },
+    account_settings::AccountSettings,
+    album::{Album, AlbumID, AlbumPrivacy},
+    basic::Basic,
+    comment::{Comment, CommentID},
+    common::{AccountID, Username},
+    custom_gallery::{CustomGalleryItem, FavoriteItem},
+    gallery_profile::GalleryProfile,
+    image::{Image, ImageID},
+    notification::ReplyNotification,
 };
 
 use crate::{
     client::{AuthenticatedClient, BasicClient, SortPreference},
+    endpoint::{Endpoint, Envelope},
     error::ClientError,
     response::Response,
     traits::{Client, RegisteredClient},
 };
 
+/// Username path segment of account lookups by ID
+///
+/// The "Account Base" section of the Imgur API documentation (<https://apidocs.imgur.com>)
+/// mentions an `account_id` query parameter to look an account up by ID, but not how the
+/// username path segment should be filled in that case. The lookups by ID send this placeholder,
+/// expecting the API to resolve the account from `account_id` alone.
+const ACCOUNT_ID_PLACEHOLDER: &str = "_";
+
+/// Endpoint of an account lookup by ID, under `3/account/_` with the `account_id` query parameter
+fn account_by_id_endpoint<T>(path: &str, account_id: AccountID) -> Endpoint<T> {
+    Endpoint::get(format!("3/account/{}{}", ACCOUNT_ID_PLACEHOLDER, path))
+        .query("account_id", account_id)
+}
+
+/// Path of the account submissions, relative to the account
+fn submissions_path(page: Option<u64>, sort: Option<SortPreference>) -> String {
+    let mut path = "/submissions".to_owned();
+    if let Some(page) = page {
+        path = format!("{}/{}", path, page);
+    }
+    if let Some(sort) = sort {
+        path = format!("{}/{}", path, sort);
+    }
+    path
+}
+
+/// Change set of the account settings
+///
+/// Only the fields that were set are sent to the API.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccountSettingsUpdate {
+    bio: Option<String>,
+    public_images: Option<bool>,
+    messaging_enabled: Option<bool>,
+    album_privacy: Option<AlbumPrivacy>,
+    accepted_gallery_terms: Option<bool>,
+    username: Option<String>,
+    show_mature: Option<bool>,
+    newsletter_subscribed: Option<bool>,
+}
+
+impl AccountSettingsUpdate {
+    /// Empty change set constructor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The biography of the user, is displayed in the gallery profile page
+    pub fn bio<T: Into<String>>(mut self, bio: T) -> Self {
+        self.bio = Some(bio.into());
+        self
+    }
+
+    /// Set the users images to private or public by default
+    pub fn public_images(mut self, public_images: bool) -> Self {
+        self.public_images = Some(public_images);
+        self
+    }
+
+    /// Allows the user to enable or disable private messages
+    pub fn messaging_enabled(mut self, messaging_enabled: bool) -> Self {
+        self.messaging_enabled = Some(messaging_enabled);
+        self
+    }
+
+    /// Sets the default privacy level of albums the users creates
+    pub fn album_privacy(mut self, album_privacy: AlbumPrivacy) -> Self {
+        self.album_privacy = Some(album_privacy);
+        self
+    }
+
+    /// The user agreement to the Imgur Gallery terms
+    pub fn accepted_gallery_terms(mut self, accepted_gallery_terms: bool) -> Self {
+        self.accepted_gallery_terms = Some(accepted_gallery_terms);
+        self
+    }
+
+    /// A valid Imgur username (between 4 and 63 alphanumeric characters)
+    pub fn username<T: Into<String>>(mut self, username: T) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Toggle display of mature images in gallery list endpoints
+    pub fn show_mature(mut self, show_mature: bool) -> Self {
+        self.show_mature = Some(show_mature);
+        self
+    }
+
+    /// Toggle subscription to email newsletter
+    pub fn newsletter_subscribed(mut self, newsletter_subscribed: bool) -> Self {
+        self.newsletter_subscribed = Some(newsletter_subscribed);
+        self
+    }
+
+    /// Whether no field was set
+    pub fn is_empty(&self) -> bool {
+        self.to_form_parameters().is_empty()
+    }
+
+    /// Encode the changes to the respective form parameters, skipping unset values
+    pub fn to_form_parameters(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(bio) = &self.bio {
+            params.push(("bio", bio.clone()));
+        }
+        if let Some(public_images) = self.public_images {
+            params.push(("public_images", public_images.to_string()));
+        }
+        if let Some(messaging_enabled) = self.messaging_enabled {
+            params.push(("messaging_enabled", messaging_enabled.to_string()));
+        }
+        if let Some(album_privacy) = self.album_privacy {
+            params.push(("album_privacy", album_privacy.to_string()));
+        }
+        if let Some(accepted_gallery_terms) = self.accepted_gallery_terms {
+            params.push(("accepted_gallery_terms", accepted_gallery_terms.to_string()));
+        }
+        if let Some(username) = &self.username {
+            params.push(("username", username.clone()));
+        }
+        if let Some(show_mature) = self.show_mature {
+            params.push(("show_mature", show_mature.to_string()));
+        }
+        if let Some(newsletter_subscribed) = self.newsletter_subscribed {
+            params.push(("newsletter_subscribed", newsletter_subscribed.to_string()));
+        }
+        params
+    }
+}
+
 /// Account API client
 #[async_trait]
 pub trait AccountClient: Client {
@@ -25,88 +164,228 @@ pub trait AccountClient: Client {
         &self,
         username: Username,
     ) -> Result<Response<Account>, ClientError> {
-        let res = self
-            .get_client()
-            .get(&format!("https://api.imgur.com/3/account/{}", username))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
-        let headers = res.headers().clone();
-        let content = res.json().await?;
-
-        Ok(Response { content, headers })
+        self.execute(Endpoint::get(format!("3/account/{}", username)))
+            .await
     }
 
-    // /// Get account by user id
-    // async fn get_account_by_id(&self, _user_id: AccountID) -> Result<serde_json::Value, ClientError> {
-    //     unimplemented!()
-    //
-    //     // TODO: can't get how to implement it. Unclear API documentation.
-    //     // self.get_client()
-    //     //     .get(&format!("https://api.imgur.com/3/account/{}?user_id=True", user_id))
-    //     //     .headers(self.get_headers()?)
-    //     //     .send().await?
-    //     //     .json::<serde_json::Value>().await
-    //     //     .map_err(Into::into)
-    // }
+    /// Get account information by account ID
+    ///
+    /// Requests `3/account/_?account_id={account_id}`, with `_` as placeholder username:
+    /// only the `account_id` query parameter is documented by the API.
+    async fn get_account_by_id(
+        &self,
+        account_id: AccountID,
+    ) -> Result<Response<Account>, ClientError> {
+        self.execute(account_by_id_endpoint("", account_id)).await
+    }
 
     /// Get account block status
     async fn get_account_block_status(
         &self,
         username: &str,
     ) -> Result<Response<BlockedStatus>, ClientError> {
-        #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
-        #[serde(rename_all = "snake_case")]
-        #[serde(deny_unknown_fields)]
-        struct Wrapper {
-            data: BlockedStatus,
+        self.execute(
+            Endpoint::get(format!("account/v1/{}/block", username)).envelope(Envelope::Data),
+        )
+        .await
+    }
+
+    /// Get account images
+    async fn get_account_images(
+        &self,
+        username: &str,
+    ) -> Result<Response<Vec<Image>>, ClientError> {
+        self.execute(Endpoint::get(format!("3/account/{}/images", &username)))
+            .await
+    }
+
+    /// Account image IDs
+    ///
+    /// Returns an array of Image IDs that are associated with the account.
+    async fn get_account_image_ids(
+        &self,
+        username: &str,
+        page: Option<u64>,
+    ) -> Result<Response<Vec<ImageID>>, ClientError> {
+        let mut url = format!("3/account/{}/images/ids", username);
+        if let Some(page) = page {
+            url = format!("{}/{}", url, page);
         }
 
-        let res = self
-            .get_client()
-            .get(&format!(
-                "https://api.imgur.com/account/v1/{}/block",
-                username
-            ))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
+        self.execute(Endpoint::get(url)).await
+    }
 
-        let headers = res.headers().clone();
-        let status = res.status();
-        let data = Data::Content(res.json::<Wrapper>().await?.data);
+    /// Account image count
+    ///
+    /// Returns the total number of images associated with the account.
+    async fn get_account_image_count(&self, username: &str) -> Result<Response<u64>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/account/{}/images/count",
+            username
+        )))
+        .await
+    }
 
-        Ok(Response {
-            content: Basic {
-                data,
-                success: status.is_success(),
-                status: status.as_u16(),
-            },
-            headers,
-        })
+    /// Account submissions
+    ///
+    /// Return the images a user has submitted to the gallery. You can add sorting as well after paging. Sorts can be: newest (default), oldest, worst, best.
+    async fn get_account_submissions(
+        &self,
+        username: &str,
+        page: Option<u64>,
+        sort: Option<SortPreference>,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/account/{}{}",
+            username,
+            submissions_path(page, sort)
+        )))
+        .await
     }
 
-    /// Get account images
+    /// Account submissions by account ID
     ///
-    /// TODO: missing response typing
-    async fn get_account_images(
+    /// Return the images a user has submitted to the gallery, looking the user up by account ID.
+    /// Requests `3/account/_/submissions/{page}/{sort}?account_id={account_id}`, with `_` as
+    /// placeholder username as in `get_account_by_id`.
+    async fn get_account_submissions_by_id(
+        &self,
+        account_id: AccountID,
+        page: Option<u64>,
+        sort: Option<SortPreference>,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(account_by_id_endpoint(
+            &submissions_path(page, sort),
+            account_id,
+        ))
+        .await
+    }
+
+    /// Account gallery profile by account ID
+    ///
+    /// Returns the totals for a users gallery information, looking the user up by account ID.
+    /// Requests `3/account/_/gallery_profile?account_id={account_id}`, with `_` as placeholder
+    /// username as in `get_account_by_id`.
+    async fn get_gallery_profile_by_id(
+        &self,
+        account_id: AccountID,
+    ) -> Result<Response<GalleryProfile>, ClientError> {
+        self.execute(account_by_id_endpoint("/gallery_profile", account_id))
+            .await
+    }
+
+    /// Account gallery profile
+    ///
+    /// Returns the totals for a users gallery information.
+    async fn get_gallery_profile(
         &self,
         username: &str,
-    ) -> Result<Response<Vec<serde_json::Value>>, ClientError> {
-        let res = self
-            .get_client()
-            .get(&format!(
-                "https://api.imgur.com/3/account/{}/images",
-                &username
-            ))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
+    ) -> Result<Response<GalleryProfile>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/account/{}/gallery_profile",
+            username
+        )))
+        .await
+    }
 
-        let headers = res.headers().clone();
-        let content = res.json().await?;
+    /// Account comments
+    ///
+    /// Return the comments the user has created.
+    /// The sort defaults to newest if only the page is given.
+    async fn get_account_comments(
+        &self,
+        username: &str,
+        sort: Option<SortPreference>,
+        page: Option<u64>,
+    ) -> Result<Response<Vec<Comment>>, ClientError> {
+        let mut url = format!("3/account/{}/comments", username);
+        if sort.is_some() || page.is_some() {
+            url = format!("{}/{}", url, sort.unwrap_or_default());
+        }
+        if let Some(page) = page {
+            url = format!("{}/{}", url, page);
+        }
 
-        Ok(Response { content, headers })
+        self.execute(Endpoint::get(url)).await
+    }
+
+    /// Account comment IDs
+    ///
+    /// Return an array of all of the comment IDs.
+    /// The sort defaults to newest if only the page is given.
+    async fn get_account_comment_ids(
+        &self,
+        username: &str,
+        sort: Option<SortPreference>,
+        page: Option<u64>,
+    ) -> Result<Response<Vec<CommentID>>, ClientError> {
+        let mut url = format!("3/account/{}/comments/ids", username);
+        if sort.is_some() || page.is_some() {
+            url = format!("{}/{}", url, sort.unwrap_or_default());
+        }
+        if let Some(page) = page {
+            url = format!("{}/{}", url, page);
+        }
+
+        self.execute(Endpoint::get(url)).await
+    }
+
+    /// Account comment count
+    ///
+    /// Return a count of all of the comments associated with the account.
+    async fn get_account_comment_count(
+        &self,
+        username: &str,
+    ) -> Result<Response<u64>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/account/{}/comments/count",
+            username
+        )))
+        .await
+    }
+
+    /// Account albums
+    ///
+    /// Get all the albums associated with the account.
+    /// Must be logged in as the user to see secret and hidden albums.
+    async fn get_account_albums(
+        &self,
+        username: &str,
+        page: Option<u64>,
+    ) -> Result<Response<Vec<Album>>, ClientError> {
+        let mut url = format!("3/account/{}/albums", username);
+        if let Some(page) = page {
+            url = format!("{}/{}", url, page);
+        }
+
+        self.execute(Endpoint::get(url)).await
+    }
+
+    /// Account album IDs
+    ///
+    /// Return an array of all of the album IDs.
+    async fn get_account_album_ids(
+        &self,
+        username: &str,
+        page: Option<u64>,
+    ) -> Result<Response<Vec<AlbumID>>, ClientError> {
+        let mut url = format!("3/account/{}/albums/ids", username);
+        if let Some(page) = page {
+            url = format!("{}/{}", url, page);
+        }
+
+        self.execute(Endpoint::get(url)).await
+    }
+
+    /// Account album count
+    ///
+    /// Return the total number of albums associated with the account.
+    async fn get_account_album_count(&self, username: &str) -> Result<Response<u64>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/account/{}/albums/count",
+            username
+        )))
+        .await
     }
 
     /// Account gallery favorites
@@ -118,10 +397,7 @@ pub trait AccountClient: Client {
         page: Option<u64>,
         sort: Option<SortPreference>,
     ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
-        let mut url = format!(
-            "https://api.imgur.com/3/account/{}/gallery_favorites",
-            username
-        );
+        let mut url = format!("3/account/{}/gallery_favorites", username);
         if let Some(page) = page {
             url = format!("{}/{}", url, page);
         }
@@ -129,25 +405,7 @@ pub trait AccountClient: Client {
             url = format!("{}/{}", url, sort);
         }
 
-        println!("{}", url);
-
-        let res = self
-            .get_client()
-            .get(&url)
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
-
-        let headers = res.headers().clone();
-        // let text = res.text().await?;
-        // println!(
-        //     "{}",
-        //     serde_json::to_string_pretty(&serde_json::from_str::<serde_json::Value>(&text)?)?
-        // );
-        // let content = serde_json::from_str(&text)?;
-        let content = res.json().await?;
-
-        Ok(Response { content, headers })
+        self.execute(Endpoint::get(url)).await
     }
 }
 
@@ -156,14 +414,9 @@ pub trait AccountClient: Client {
 pub trait AccountRegisteredClient: AccountClient + RegisteredClient {
     /// Get list of blocked accounts
     async fn get_account_blocks(&self) -> Result<Basic<AccountBlocks>, ClientError> {
-        self.get_client()
-            .get("https://api.imgur.com/3/account/me/block")
-            .headers(self.get_headers()?)
-            .send()
-            .await?
-            .json()
+        self.execute(Endpoint::get("3/account/me/block").authenticated())
             .await
-            .map_err(Into::into)
+            .map(|res| res.content)
     }
 
     /// Create a block for an account
@@ -171,20 +424,8 @@ pub trait AccountRegisteredClient: AccountClient + RegisteredClient {
         &self,
         username: &str,
     ) -> Result<Response<BlockedStatus>, ClientError> {
-        let res = self
-            .get_client()
-            .put(&format!(
-                "https://api.imgur.com/account/v1/{}/block",
-                username
-            ))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
-
-        let headers = res.headers().clone();
-        let content = res.json().await?;
-
-        Ok(Response { content, headers })
+        self.execute(Endpoint::put(format!("account/v1/{}/block", username)).authenticated())
+            .await
     }
 
     /// Remove a block for an account
@@ -192,29 +433,135 @@ pub trait AccountRegisteredClient: AccountClient + RegisteredClient {
         &self,
         username: &str,
     ) -> Result<Response<BlockedStatus>, ClientError> {
-        let res = self
-            .get_client()
-            .delete(&format!(
-                "https://api.imgur.com/account/v1/{}/block",
-                username
-            ))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
+        self.execute(Endpoint::delete(format!("account/v1/{}/block", username)).authenticated())
+            .await
+    }
 
-        let headers = res.headers().clone();
-        let content = res.json().await?;
+    /// Account settings
+    ///
+    /// Returns the account settings, only accessible if you're logged in as the user.
+    async fn get_account_settings(&self) -> Result<Response<AccountSettings>, ClientError> {
+        self.execute(Endpoint::get("3/account/me/settings").authenticated())
+            .await
+    }
 
-        Ok(Response { content, headers })
+    /// Change account settings
+    ///
+    /// Updates the account settings for the given user, only the fields set in `update` are sent.
+    async fn update_account_settings(
+        &self,
+        update: &AccountSettingsUpdate,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::put("3/account/me/settings")
+                .form(update.to_form_parameters())
+                .authenticated(),
+        )
+        .await
     }
 
-    /// Get images of the current user
-    async fn get_user_account_images(
+    /// Follow an account
+    ///
+    /// Follows the account with the given username.
+    async fn follow_account(&self, username: &str) -> Result<Response<UserFollow>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/account/me/follow/user/{}", username)).authenticated(),
+        )
+        .await
+    }
+
+    /// Unfollow an account
+    ///
+    /// Unfollows the account with the given username.
+    async fn unfollow_account(&self, username: &str) -> Result<Response<UserFollow>, ClientError> {
+        self.execute(
+            Endpoint::delete(format!("3/account/me/follow/user/{}", username)).authenticated(),
+        )
+        .await
+    }
+
+    /// Verify user's e-mail
+    ///
+    /// Checks to see if the user has verified their email address.
+    async fn get_email_verification_status(&self) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::get("3/account/me/verifyemail").authenticated())
+            .await
+    }
+
+    /// Send verification e-mail
+    ///
+    /// Sends an email to the user to verify that their email is valid to upload to gallery.
+    async fn send_verification_email(&self) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::post("3/account/me/verifyemail").authenticated())
+            .await
+    }
+
+    /// Account avatar
+    ///
+    /// Returns the current avatar and cover of the user.
+    async fn get_account_avatar(&self) -> Result<Response<AccountAvatar>, ClientError> {
+        self.execute(Endpoint::get("3/account/me/avatar").authenticated())
+            .await
+    }
+
+    /// Available avatars
+    ///
+    /// Returns the avatars the user can choose from.
+    async fn get_available_avatars(&self) -> Result<Response<AvailableAvatars>, ClientError> {
+        self.execute(Endpoint::get("3/account/me/available_avatars").authenticated())
+            .await
+    }
+
+    /// Available covers
+    ///
+    /// Returns the covers the user can choose from.
+    async fn get_available_covers(&self) -> Result<Response<AvailableCovers>, ClientError> {
+        self.execute(Endpoint::get("3/account/me/available_covers").authenticated())
+            .await
+    }
+
+    /// Update avatar and cover
+    ///
+    /// Sets the avatar or the cover of the user, by name of one of the available choices.
+    async fn update_account_avatar(
         &self,
-    ) -> Result<Response<Vec<serde_json::Value>>, ClientError> {
+        avatar: Option<&str>,
+        cover: Option<&str>,
+    ) -> Result<Response<bool>, ClientError> {
+        let params: Vec<(&str, &str)> = [("avatar", avatar), ("cover", cover)]
+            .iter()
+            .filter_map(|(k, v)| v.map(|v| (*k, v)))
+            .collect();
+
+        self.execute(
+            Endpoint::post("3/account/me/avatar")
+                .form(params)
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Get images of the current user
+    async fn get_user_account_images(&self) -> Result<Response<Vec<Image>>, ClientError> {
         self.get_account_images("me").await
     }
 
+    /// Replies
+    ///
+    /// Returns all of the reply notifications for the user.
+    /// If `new_only` is set, only the unviewed notifications are returned.
+    async fn get_account_replies(
+        &self,
+        new_only: bool,
+    ) -> Result<Response<Vec<ReplyNotification>>, ClientError> {
+        self.execute(
+            Endpoint::get("3/account/me/notifications/replies")
+                .query("new", new_only)
+                .authenticated(),
+        )
+        .await
+    }
+
     /// Get favourite galleries of the current user
     async fn get_user_gallery_favorites(
         &self,
@@ -224,14 +571,15 @@ pub trait AccountRegisteredClient: AccountClient + RegisteredClient {
         self.get_gallery_favorites("me", page, sort).await
     }
 
-    // TODO: typed implementation
     /// Get favourites of the current user
+    ///
+    /// Returns the images and albums the user has favorited, shared in the gallery or not.
     async fn get_user_favorites(
         &self,
         page: Option<u64>,
         sort: Option<SortPreference>,
-    ) -> Result<Response<serde_json::Value>, ClientError> {
-        let mut url = "https://api.imgur.com/3/account/me/favorites".to_string();
+    ) -> Result<Response<Vec<FavoriteItem>>, ClientError> {
+        let mut url = "3/account/me/favorites".to_string();
         if let Some(page) = page {
             url = format!("{}/{}", url, page);
         }
@@ -239,49 +587,16 @@ pub trait AccountRegisteredClient: AccountClient + RegisteredClient {
             url = format!("{}/{}", url, sort);
         }
 
-        println!("{}", url);
-
-        let res = self
-            .get_client()
-            .get(&url)
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
-
-        let headers = res.headers().clone();
-        println!("{:?}", headers);
-        let content = res.json().await?;
-
-        Ok(Response { content, headers })
+        self.execute(Endpoint::get(url).authenticated()).await
     }
 
-    /// Account submissions
-    ///
-    /// Return the images a user has submitted to the gallery. You can add sorting as well after paging. Sorts can be: newest (default), oldest, worst, best.
+    /// Get submissions of the current user
     async fn get_user_submissions(
         &self,
         page: Option<u64>,
         sort: Option<SortPreference>,
-    ) -> Result<Response<serde_json::Value>, ClientError> {
-        let mut url = "https://api.imgur.com/3/account/me/submissions".to_string();
-        if let Some(page) = page {
-            url = format!("{}/{}", url, page);
-        }
-        if let Some(sort) = sort {
-            url = format!("{}/{}", url, sort);
-        }
-
-        let res = self
-            .get_client()
-            .get(&url)
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
-
-        let headers = res.headers().clone();
-        let content = res.json().await?;
-
-        Ok(Response { content, headers })
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.get_account_submissions("me", page, sort).await
     }
 }
 
@@ -294,15 +609,57 @@ impl AccountRegisteredClient for AuthenticatedClient {}
 #[cfg(test)]
 mod tests {
     use crate::{
-        client::BasicClient,
+        client::{BasicClient, SortPreference},
         endpoints::{
-            account::{AccountClient, AccountRegisteredClient},
+            account::{
+                account_by_id_endpoint, submissions_path, AccountClient, AccountRegisteredClient,
+                AccountSettingsUpdate,
+            },
             authorization::AuthenticationRegisteredClient,
         },
+        traits::Client,
+    };
+    use imgurs_model::model::{
+        account::Account,
+        album::AlbumPrivacy,
+        authorization::{AccessToken, ClientID, ClientSecret, RefreshToken},
     };
-    use imgurs_model::model::authorization::{AccessToken, ClientID, ClientSecret, RefreshToken};
     use std::{convert::TryFrom, env, error::Error};
     use time::OffsetDateTime;
+    use url::Url;
+
+    #[test]
+    fn test_account_by_id_urls() -> Result<(), Box<dyn Error>> {
+        let client = BasicClient::new(ClientID("id".into()), ClientSecret("secret".into()))?
+            .with_api_url(Url::parse("http://localhost:8080/mock/")?);
+
+        assert_eq!(
+            client
+                .endpoint_url(&account_by_id_endpoint::<Account>("", 57420253))?
+                .as_str(),
+            "http://localhost:8080/mock/3/account/_?account_id=57420253"
+        );
+        assert_eq!(
+            client
+                .endpoint_url(&account_by_id_endpoint::<Account>(
+                    &submissions_path(Some(1), Some(SortPreference::Oldest)),
+                    57420253
+                ))?
+                .as_str(),
+            "http://localhost:8080/mock/3/account/_/submissions/1/oldest?account_id=57420253"
+        );
+        assert_eq!(
+            client
+                .endpoint_url(&account_by_id_endpoint::<Account>(
+                    "/gallery_profile",
+                    57420253
+                ))?
+                .as_str(),
+            "http://localhost:8080/mock/3/account/_/gallery_profile?account_id=57420253"
+        );
+
+        Ok(())
+    }
 
     #[tokio::test]
     async fn test_get_account_by_username() -> Result<(), Box<dyn Error>> {
@@ -322,19 +679,115 @@ mod tests {
         Ok(())
     }
 
-    // TODO: enable test once method is implemented
-    /*#[tokio::test]
+    #[tokio::test]
     async fn test_get_account_by_user_id() -> Result<(), Box<dyn Error>> {
-        let client_id = ClientID::from_default_env()??;
-        let client_secret = ClientSecret::from_default_env()??;
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
         let client = BasicClient::new(client_id, client_secret)?;
+        let res = client.get_account_by_id(57420253).await?.content.result()?;
+
+        println!("{:#?}", res);
+        assert_eq!(&res.url, "bertof");
+
         let res = client
-            .get_account_by_id(57420253).await?;
+            .get_gallery_profile_by_id(57420253)
+            .await?
+            .content
+            .result()?;
 
         println!("{:#?}", res);
 
         Ok(())
-    }*/
+    }
+
+    #[tokio::test]
+    async fn test_get_gallery_profile() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+        let res = client
+            .get_gallery_profile("bertof")
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_account_comments() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let count = client
+            .get_account_comment_count("bertof")
+            .await?
+            .content
+            .result()?;
+        let ids = client
+            .get_account_comment_ids("bertof", Some(SortPreference::Oldest), Some(0))
+            .await?
+            .content
+            .result()?;
+        let comments = client
+            .get_account_comments("bertof", Some(SortPreference::Oldest), Some(0))
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", comments);
+        assert!(ids.len() as u64 <= count);
+        assert_eq!(ids.len(), comments.len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_account_albums() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let count = client
+            .get_account_album_count("bertof")
+            .await?
+            .content
+            .result()?;
+        let ids = client
+            .get_account_album_ids("bertof", None)
+            .await?
+            .content
+            .result()?;
+        let albums = client
+            .get_account_albums("bertof", None)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", albums);
+        assert!(ids.len() as u64 <= count);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_account_submissions_by_username() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+        let res = client
+            .get_account_submissions("bertof", Some(0), Some(SortPreference::Newest))
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
 
     #[tokio::test]
     async fn test_get_account_block_status() -> Result<(), Box<dyn Error>> {
@@ -398,6 +851,83 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_account_settings_update_parameters() {
+        assert!(AccountSettingsUpdate::new().is_empty());
+
+        let update = AccountSettingsUpdate::new()
+            .bio("Hello there")
+            .album_privacy(AlbumPrivacy::Hidden)
+            .show_mature(false);
+
+        assert_eq!(
+            update.to_form_parameters(),
+            vec![
+                ("bio", "Hello there".to_string()),
+                ("album_privacy", "hidden".to_string()),
+                ("show_mature", "false".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_account_settings() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client.get_account_settings().await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_email_verification_status() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client
+            .get_email_verification_status()
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_available_avatars() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client.get_available_avatars().await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_get_account_images() -> Result<(), Box<dyn Error>> {
         let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
@@ -416,6 +946,52 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_get_account_image_ids() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let count = client
+            .get_account_image_count("me")
+            .await?
+            .content
+            .result()?;
+        let ids = client
+            .get_account_image_ids("me", Some(0))
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", ids);
+        assert!(ids.len() as u64 <= count);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_account_replies() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client.get_account_replies(false).await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
     // TODO: enable test once parsing is corrected
     #[ignore]
     #[tokio::test]
@@ -0,0 +1,301 @@
+//! Album API implementation
+
+use async_trait::async_trait;
+
+use imgurs_model::model::{
+    album::{Album, AlbumID, AlbumLayout, AlbumPrivacy, CreatedAlbum},
+    common::FavoriteStatus,
+    image::{Image, ImageID},
+};
+
+use crate::{
+    client::{AuthenticatedClient, BasicClient},
+    endpoint::Endpoint,
+    error::ClientError,
+    response::Response,
+    traits::{Client, RegisteredClient},
+};
+
+/// Images referenced by an album operation
+///
+/// Anonymous clients can only reference the images they uploaded through their deletehash,
+/// authenticated clients can use the ids of the images they own.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AlbumImages {
+    /// Image ids, for images owned by the authenticated user
+    Ids(Vec<ImageID>),
+    /// Image deletehashes, for anonymous images
+    DeleteHashes(Vec<String>),
+}
+
+impl AlbumImages {
+    /// Encode the images to the respective form parameters
+    pub fn to_form_parameters(&self) -> Vec<(&str, String)> {
+        match self {
+            AlbumImages::Ids(ids) => ids.iter().map(|id| ("ids[]", id.to_string())).collect(),
+            AlbumImages::DeleteHashes(hashes) => hashes
+                .iter()
+                .map(|hash| ("deletehashes[]", hash.clone()))
+                .collect(),
+        }
+    }
+
+    /// Encode the images to the respective query parameter, as a comma separated list
+    pub fn to_query_parameters(&self) -> Vec<(&str, String)> {
+        match self {
+            AlbumImages::Ids(ids) => vec![(
+                "ids",
+                ids.iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )],
+            AlbumImages::DeleteHashes(hashes) => vec![("deletehashes", hashes.join(","))],
+        }
+    }
+}
+
+/// Album creation and update details
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AlbumDetails {
+    /// Images of the album
+    pub images: Option<AlbumImages>,
+    /// The title of the album
+    pub title: Option<String>,
+    /// The description of the album
+    pub description: Option<String>,
+    /// The privacy level of the album
+    pub privacy: Option<AlbumPrivacy>,
+    /// The view layout of the album
+    pub layout: Option<AlbumLayout>,
+    /// The id of the image to use as the album cover
+    pub cover: Option<ImageID>,
+}
+
+impl AlbumDetails {
+    /// Encode the details to the respective form parameters, skipping unset values
+    pub fn to_form_parameters(&self) -> Vec<(&str, String)> {
+        let mut params = self
+            .images
+            .as_ref()
+            .map(AlbumImages::to_form_parameters)
+            .unwrap_or_default();
+        if let Some(title) = &self.title {
+            params.push(("title", title.clone()));
+        }
+        if let Some(description) = &self.description {
+            params.push(("description", description.clone()));
+        }
+        if let Some(privacy) = self.privacy {
+            params.push(("privacy", privacy.to_string()));
+        }
+        if let Some(layout) = self.layout {
+            params.push(("layout", layout.to_string()));
+        }
+        if let Some(cover) = &self.cover {
+            params.push(("cover", cover.to_string()));
+        }
+        params
+    }
+}
+
+/// Album API client
+///
+/// Operations on existing albums take an `album_hash`: the album id for albums owned by the
+/// authenticated user, the deletehash for anonymous albums.
+#[async_trait]
+pub trait AlbumClient: Client {
+    /// Album
+    ///
+    /// Get information about a specific album.
+    async fn get_album(&self, album_id: &AlbumID) -> Result<Response<Album>, ClientError> {
+        self.execute(Endpoint::get(format!("3/album/{}", album_id)))
+            .await
+    }
+
+    /// Album images
+    ///
+    /// Return all of the images in the album.
+    async fn get_album_images(
+        &self,
+        album_id: &AlbumID,
+    ) -> Result<Response<Vec<Image>>, ClientError> {
+        self.execute(Endpoint::get(format!("3/album/{}/images", album_id)))
+            .await
+    }
+
+    /// Album creation
+    ///
+    /// Create a new album.
+    /// Anonymous albums are returned with a deletehash, required for any further edit.
+    async fn create_album(
+        &self,
+        details: &AlbumDetails,
+    ) -> Result<Response<CreatedAlbum>, ClientError> {
+        self.execute(Endpoint::post("3/album").form(details.to_form_parameters()))
+            .await
+    }
+
+    /// Update album
+    ///
+    /// Update the information of an album, only the set details are changed.
+    async fn update_album(
+        &self,
+        album_hash: &str,
+        details: &AlbumDetails,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::put(format!("3/album/{}", album_hash)).form(details.to_form_parameters()),
+        )
+        .await
+    }
+
+    /// Album deletion
+    ///
+    /// Delete an album, the images it contains are kept.
+    async fn delete_album(&self, album_hash: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::delete(format!("3/album/{}", album_hash)))
+            .await
+    }
+
+    /// Set album images
+    ///
+    /// Sets the images for an album, removing all other images.
+    async fn set_album_images(
+        &self,
+        album_hash: &str,
+        images: &AlbumImages,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/album/{}", album_hash)).form(images.to_form_parameters()),
+        )
+        .await
+    }
+
+    /// Add images to an album
+    ///
+    /// Takes parameter `images` and adds it to the album.
+    async fn add_album_images(
+        &self,
+        album_hash: &str,
+        images: &AlbumImages,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/album/{}/add", album_hash)).form(images.to_form_parameters()),
+        )
+        .await
+    }
+
+    /// Remove images from an album
+    ///
+    /// Takes parameter `images` and removes it from the album.
+    /// The images are sent as query parameters, as DELETE request bodies are often dropped.
+    async fn remove_album_images(
+        &self,
+        album_hash: &str,
+        images: &AlbumImages,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::delete(format!("3/album/{}/remove_images", album_hash))
+                .query_pairs(images.to_query_parameters()),
+        )
+        .await
+    }
+}
+
+/// Registered client album API client
+#[async_trait]
+pub trait AlbumRegisteredClient: AlbumClient + RegisteredClient {
+    /// Favorite an album
+    ///
+    /// Favorites an album with the given ID, or removes it from the favorites if already present.
+    async fn favorite_album(
+        &self,
+        album_id: &AlbumID,
+    ) -> Result<Response<FavoriteStatus>, ClientError> {
+        self.execute(Endpoint::post(format!("3/album/{}/favorite", album_id)).authenticated())
+            .await
+    }
+}
+
+impl AlbumClient for BasicClient {}
+
+impl AlbumClient for AuthenticatedClient {}
+
+impl AlbumRegisteredClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::BasicClient,
+        endpoints::album::{AlbumClient, AlbumDetails, AlbumImages},
+    };
+    use imgurs_model::model::{
+        album::AlbumPrivacy,
+        authorization::{ClientID, ClientSecret},
+    };
+    use std::{convert::TryFrom, env, error::Error};
+
+    #[test]
+    fn test_album_images_query_parameters() {
+        let images = AlbumImages::Ids(vec!["abc".into(), "def".into()]);
+        assert_eq!(
+            images.to_query_parameters(),
+            vec![("ids", "abc,def".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_album() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client.get_album(&"z6B0j".into()).await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_album_images() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_album_images(&"z6B0j".into())
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[tokio::test]
+    async fn test_create_and_delete_anonymous_album() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let details = AlbumDetails {
+            title: Some("Imgurs test album".to_string()),
+            privacy: Some(AlbumPrivacy::Hidden),
+            ..AlbumDetails::default()
+        };
+        let res = client.create_album(&details).await?.content.result()?;
+        println!("{:#?}", res);
+
+        let delete_hash = res
+            .delete_hash
+            .expect("Anonymous albums return a deletehash");
+        let res = client.delete_album(&delete_hash).await?.content.result()?;
+        assert!(res);
+
+        Ok(())
+    }
+}
@@ -1,23 +1,22 @@
 //! Authorization API wrapper
 use crate::{
     client::{AuthenticatedClient, BasicClient},
+    endpoint::{Endpoint, Envelope},
     error::ClientError,
     response::Response,
     traits::{Client, RegisteredClient},
 };
 use async_trait::async_trait;
-use imgurs_model::model::{
-    authorization::{AuthorizationCode, AuthorizationResponse, PINCode, RefreshResponse},
-    basic::{Basic, Data},
+use imgurs_model::model::authorization::{
+    AuthorizationCode, AuthorizationResponse, PINCode, RefreshResponse,
 };
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{Deserialize, Serialize};
 use time::{Duration, OffsetDateTime};
-use tracing::debug;
 use url::Url;
 
-/// Client authorization API endpoint
+/// Client authorization API endpoint, with the default OAuth base URL
 pub const CLIENT_AUTHORIZATION_URL: &str = "https://api.imgur.com/oauth2/authorize";
-/// Client authorization via token (pin or authentication code) API endpoint
+/// Client authorization via token (pin or authentication code) API endpoint, with the default OAuth base URL
 pub const CLIENT_TOKEN_URL: &str = "https://api.imgur.com/oauth2/token";
 /// Client authentication token refresh timeout in minutes
 pub const REFRESH_TIMEOUT: i64 = 5;
@@ -50,40 +49,6 @@ impl Method {
     }
 }
 
-async fn parse_response_or_error<T: DeserializeOwned>(
-    res: reqwest::Response,
-) -> Result<Response<T>, ClientError> {
-    let status = res.status();
-    let headers = res.headers().clone();
-
-    let text = res.text().await?;
-
-    debug!("Body: {}", text);
-
-    // Parse for a T
-    let val = serde_json::from_str(&text);
-
-    // If correct return Response with AuthorizationResponse
-    if let Ok(val) = val {
-        Ok(Response {
-            content: Basic {
-                data: Data::Content(val),
-                success: status.is_success(),
-                status: status.as_u16(),
-            },
-            headers,
-        })
-    } else {
-        // Parse for a BasicData
-        serde_json::from_str(&text)
-            .map(|data| Response {
-                content: data,
-                headers,
-            })
-            .map_err(Into::into)
-    }
-}
-
 /// Authentication API client
 #[async_trait]
 pub trait AuthenticationClient: Client {
@@ -98,17 +63,14 @@ pub trait AuthenticationClient: Client {
         method: Method,
         state: Option<&str>,
     ) -> Result<Url, ClientError> {
-        let url = format!(
-            "{}?response_type={}&client_id={}{}",
-            CLIENT_AUTHORIZATION_URL,
-            method.to_url_parameter(),
-            self.get_settings().client_id.0,
-            match state {
-                Some(v) => format!("&state={}", v),
-                None => "".to_string(),
-            }
-        );
-        Url::parse(&url).map_err(Into::into)
+        let mut url = self.oauth_url("authorize")?;
+        url.query_pairs_mut()
+            .append_pair("response_type", method.to_url_parameter())
+            .append_pair("client_id", &self.get_settings().client_id.0);
+        if let Some(state) = state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+        Ok(url)
     }
 
     /// Request client authorization through an authorization code
@@ -116,20 +78,21 @@ pub trait AuthenticationClient: Client {
         &self,
         code: AuthorizationCode,
     ) -> Result<Response<AuthorizationResponse>, ClientError> {
-        let res = self
-            .get_client()
-            .post(CLIENT_TOKEN_URL)
-            .headers(self.get_headers()?)
-            .form(&[
-                ("client_id", &self.get_settings().client_id.0),
-                ("client_secret", &self.get_settings().client_secret.0),
-                ("grant_type", &"authorization_code".to_owned()),
-                ("code", &code.0),
-            ])
-            .send()
-            .await?;
-
-        parse_response_or_error(res).await
+        self.execute(
+            Endpoint::post("token")
+                .oauth()
+                .form([
+                    ("client_id", self.get_settings().client_id.to_string()),
+                    (
+                        "client_secret",
+                        self.get_settings().client_secret.to_string(),
+                    ),
+                    ("grant_type", "authorization_code".to_owned()),
+                    ("code", code.0),
+                ])
+                .envelope(Envelope::Bare),
+        )
+        .await
     }
 
     /// Request client authorization through a pin code
@@ -137,20 +100,21 @@ pub trait AuthenticationClient: Client {
         &self,
         code: PINCode,
     ) -> Result<Response<AuthorizationResponse>, ClientError> {
-        let res = self
-            .get_client()
-            .post(CLIENT_TOKEN_URL)
-            .headers(self.get_headers()?)
-            .form(&[
-                ("client_id", &self.get_settings().client_id.0),
-                ("client_secret", &self.get_settings().client_secret.0),
-                ("grant_type", &"pin".to_owned()),
-                ("pin", &code.0),
-            ])
-            .send()
-            .await?;
-
-        parse_response_or_error(res).await
+        self.execute(
+            Endpoint::post("token")
+                .oauth()
+                .form([
+                    ("client_id", self.get_settings().client_id.to_string()),
+                    (
+                        "client_secret",
+                        self.get_settings().client_secret.to_string(),
+                    ),
+                    ("grant_type", "pin".to_owned()),
+                    ("pin", code.0),
+                ])
+                .envelope(Envelope::Bare),
+        )
+        .await
     }
 }
 
@@ -159,26 +123,25 @@ pub trait AuthenticationClient: Client {
 pub trait AuthenticationRegisteredClient: AuthenticationClient + RegisteredClient {
     /// Refresh the client token
     async fn refresh_token(&self) -> Result<Response<RefreshResponse>, ClientError> {
-        let res = self
-            .get_client()
-            .post(CLIENT_TOKEN_URL)
-            .headers(self.get_headers()?)
-            .form(&[
-                ("client_id", &self.get_settings().client_id.to_string()),
-                (
-                    "client_secret",
-                    &self.get_settings().client_secret.to_string(),
-                ),
-                (
-                    "refresh_token",
-                    &self.get_authentication_settings().refresh_token.to_string(),
-                ),
-                ("grant_type", &"refresh_token".to_string()),
-            ])
-            .send()
-            .await?;
-
-        parse_response_or_error(res).await
+        self.execute(
+            Endpoint::post("token")
+                .oauth()
+                .form([
+                    ("client_id", self.get_settings().client_id.to_string()),
+                    (
+                        "client_secret",
+                        self.get_settings().client_secret.to_string(),
+                    ),
+                    ("grant_type", "refresh_token".to_owned()),
+                    (
+                        "refresh_token",
+                        self.get_authentication_settings().refresh_token.to_string(),
+                    ),
+                ])
+                .envelope(Envelope::Bare)
+                .authenticated(),
+        )
+        .await
     }
 
     /// Chain refresh of tokens if necessary
@@ -193,7 +156,7 @@ pub trait AuthenticationRegisteredClient: AuthenticationClient + RegisteredClien
             Ok(self)
         } else {
             let mut client = self.clone();
-            let res = client.refresh_token().await?.content.result()?;
+            let res = client.refresh_token().await?.into_result()?;
             client.update_authentication_token(res.access_token, res.expires_in);
             Ok(client)
         }
@@ -0,0 +1,163 @@
+//! Comment API implementation
+
+use async_trait::async_trait;
+
+use imgurs_model::model::{
+    comment::{Comment, CommentID, CreatedComment},
+    common::{ReportReason, Vote},
+};
+
+use crate::{
+    client::{AuthenticatedClient, BasicClient},
+    endpoint::Endpoint,
+    error::ClientError,
+    response::Response,
+    traits::{Client, RegisteredClient},
+};
+
+/// Comment API client
+#[async_trait]
+pub trait CommentClient: Client {
+    /// Comment
+    ///
+    /// Get information about a specific comment.
+    async fn get_comment(&self, comment_id: CommentID) -> Result<Response<Comment>, ClientError> {
+        self.execute(Endpoint::get(format!("3/comment/{}", comment_id)))
+            .await
+    }
+
+    /// Comment replies
+    ///
+    /// Get the comment with all of the replies for the comment in its `children`.
+    async fn get_comment_replies(
+        &self,
+        comment_id: CommentID,
+    ) -> Result<Response<Comment>, ClientError> {
+        self.execute(Endpoint::get(format!("3/comment/{}/replies", comment_id)))
+            .await
+    }
+}
+
+/// Registered client comment API client
+#[async_trait]
+pub trait CommentRegisteredClient: CommentClient + RegisteredClient {
+    /// Comment creation
+    ///
+    /// Creates a new comment on an image or album, optionally as a reply to `parent_id`.
+    async fn create_comment(
+        &self,
+        image_id: &str,
+        comment: &str,
+        parent_id: Option<CommentID>,
+    ) -> Result<Response<CreatedComment>, ClientError> {
+        let mut params = vec![
+            ("image_id", image_id.to_string()),
+            ("comment", comment.to_string()),
+        ];
+        if let Some(parent_id) = parent_id {
+            params.push(("parent_id", parent_id.to_string()));
+        }
+
+        self.execute(Endpoint::post("3/comment").form(params).authenticated())
+            .await
+    }
+
+    /// Reply creation
+    ///
+    /// Create a reply for the given comment.
+    async fn reply_to_comment(
+        &self,
+        comment_id: CommentID,
+        image_id: &str,
+        comment: &str,
+    ) -> Result<Response<CreatedComment>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/comment/{}", comment_id))
+                .form([("image_id", image_id), ("comment", comment)])
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Vote on a comment
+    ///
+    /// Vote on a comment. `Vote::Veto` removes a previous vote.
+    async fn vote_comment(
+        &self,
+        comment_id: CommentID,
+        vote: Vote,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/comment/{}/vote/{}", comment_id, vote)).authenticated(),
+        )
+        .await
+    }
+
+    /// Report a comment
+    ///
+    /// Report a comment for being inappropriate.
+    async fn report_comment(
+        &self,
+        comment_id: CommentID,
+        reason: ReportReason,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/comment/{}/report", comment_id))
+                .form([("reason", reason.to_string())])
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Comment deletion
+    ///
+    /// Delete a comment by the given id.
+    async fn delete_comment(&self, comment_id: CommentID) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::delete(format!("3/comment/{}", comment_id)).authenticated())
+            .await
+    }
+}
+
+impl CommentClient for BasicClient {}
+
+impl CommentClient for AuthenticatedClient {}
+
+impl CommentRegisteredClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::BasicClient, endpoints::comment::CommentClient};
+    use imgurs_model::model::authorization::{ClientID, ClientSecret};
+    use std::{convert::TryFrom, env, error::Error};
+
+    #[tokio::test]
+    async fn test_get_comment() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client.get_comment(1938633683).await?.content.result()?;
+
+        println!("{:#?}", res);
+        assert_eq!(res.id, 1938633683);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_comment_replies() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_comment_replies(1938633683)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Conversation API implementation
+
+use async_trait::async_trait;
+
+use imgurs_model::model::conversation::{Conversation, ConversationEntry, ConversationID};
+
+use crate::{
+    client::AuthenticatedClient, endpoint::Endpoint, error::ClientError, response::Response,
+    traits::RegisteredClient,
+};
+
+/// Registered client conversation API client
+#[async_trait]
+pub trait ConversationRegisteredClient: RegisteredClient {
+    /// Conversation list
+    ///
+    /// Get list of all conversations for the logged in user.
+    async fn get_conversations(&self) -> Result<Response<Conversation>, ClientError> {
+        self.execute(Endpoint::get("3/conversations").authenticated())
+            .await
+    }
+
+    /// Get conversation
+    ///
+    /// Get information about a specific conversation, including its messages.
+    /// Messages are paged: the returned entry reports the next `page` and whether the beginning
+    /// of the thread has been reached (`done`).
+    /// The first page is requested when only an `offset` is given.
+    async fn get_conversation(
+        &self,
+        conversation_id: ConversationID,
+        page: Option<u64>,
+        offset: Option<u64>,
+    ) -> Result<Response<ConversationEntry>, ClientError> {
+        let mut url = format!("3/conversations/{}", conversation_id);
+        if let Some(page) = page.or(offset.map(|_| 1)) {
+            url = format!("{}/{}", url, page);
+            if let Some(offset) = offset {
+                url = format!("{}/{}", url, offset);
+            }
+        }
+
+        self.execute(Endpoint::get(url).authenticated()).await
+    }
+
+    /// Message creation
+    ///
+    /// Create a new message, starting a conversation with the user if none exists.
+    async fn send_message(
+        &self,
+        recipient: &str,
+        body: &str,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/conversations/{}", recipient))
+                .form([("body", body)])
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Delete conversation
+    ///
+    /// Delete a conversation by the given id.
+    async fn delete_conversation(
+        &self,
+        conversation_id: ConversationID,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::delete(format!("3/conversations/{}", conversation_id)).authenticated(),
+        )
+        .await
+    }
+
+    /// Report sender
+    ///
+    /// Report a user for sending messages that are against the Terms of Service.
+    async fn report_sender(&self, username: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::post(format!("3/conversations/report/{}", username)).authenticated())
+            .await
+    }
+
+    /// Block sender
+    ///
+    /// Block the user from sending messages to the user that is logged in.
+    async fn block_sender(&self, username: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::post(format!("3/conversations/block/{}", username)).authenticated())
+            .await
+    }
+}
+
+impl ConversationRegisteredClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::BasicClient,
+        endpoints::{
+            authorization::AuthenticationRegisteredClient,
+            conversation::ConversationRegisteredClient,
+        },
+    };
+    use imgurs_model::model::authorization::{AccessToken, ClientID, ClientSecret, RefreshToken};
+    use std::{convert::TryFrom, env, error::Error};
+    use time::OffsetDateTime;
+
+    #[tokio::test]
+    async fn test_get_conversations() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client.get_conversations().await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+}
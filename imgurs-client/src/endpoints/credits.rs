@@ -0,0 +1,51 @@
+//! Credits API implementation
+
+use async_trait::async_trait;
+
+use imgurs_model::model::credits::Credits;
+
+use crate::{
+    client::{AuthenticatedClient, BasicClient},
+    endpoint::Endpoint,
+    error::ClientError,
+    response::Response,
+    traits::Client,
+};
+
+/// Credits API client
+#[async_trait]
+pub trait CreditsClient: Client {
+    /// Credits
+    ///
+    /// Returns the current rate limit credits of the client and, if authenticated, of the user.
+    async fn get_credits(&self) -> Result<Response<Credits>, ClientError> {
+        self.execute(Endpoint::get("3/credits")).await
+    }
+}
+
+impl CreditsClient for BasicClient {}
+
+impl CreditsClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::BasicClient, endpoints::credits::CreditsClient};
+    use imgurs_model::model::authorization::{ClientID, ClientSecret};
+    use std::{convert::TryFrom, env, error::Error};
+
+    #[tokio::test]
+    async fn test_get_credits() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client.get_credits().await?;
+
+        println!("{:#?}", res.rate_limit());
+        let credits = res.content.result()?;
+        println!("{:#?}", credits);
+        assert!(credits.client_remaining <= credits.client_limit);
+
+        Ok(())
+    }
+}
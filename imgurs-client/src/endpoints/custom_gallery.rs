@@ -0,0 +1,139 @@
+//! Custom gallery API implementation
+
+use async_trait::async_trait;
+
+use imgurs_model::model::custom_gallery::CustomGallery;
+
+use crate::{
+    client::{AuthenticatedClient, GallerySort, Window},
+    endpoint::Endpoint,
+    error::ClientError,
+    response::Response,
+    traits::RegisteredClient,
+};
+
+/// Registered client custom gallery API client
+#[async_trait]
+pub trait CustomGalleryRegisteredClient: RegisteredClient {
+    /// Custom gallery
+    ///
+    /// View images for the current user's custom gallery.
+    /// `window` is only used with the `Top` sort.
+    async fn get_custom_gallery(
+        &self,
+        sort: GallerySort,
+        window: Window,
+        page: u64,
+    ) -> Result<Response<CustomGallery>, ClientError> {
+        self.execute(
+            Endpoint::get(format!("3/g/custom/{}/{}/{}", sort, window, page)).authenticated(),
+        )
+        .await
+    }
+
+    /// Filtered out gallery
+    ///
+    /// Retrieve user's filtered out gallery.
+    /// `window` is only used with the `Top` sort.
+    async fn get_filtered_out_gallery(
+        &self,
+        sort: GallerySort,
+        window: Window,
+        page: u64,
+    ) -> Result<Response<CustomGallery>, ClientError> {
+        self.execute(
+            Endpoint::get(format!("3/g/filtered/{}/{}/{}", sort, window, page)).authenticated(),
+        )
+        .await
+    }
+
+    /// Custom gallery add
+    ///
+    /// Add tags to a user's custom gallery.
+    async fn add_custom_gallery_tags(&self, tags: &[&str]) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::put("3/g/custom/add_tags")
+                .form([("tags", tags.join(","))])
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Custom gallery remove
+    ///
+    /// Remove tags from a custom gallery.
+    /// The tags are sent as a query parameter, as DELETE request bodies are often dropped.
+    async fn remove_custom_gallery_tags(
+        &self,
+        tags: &[&str],
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::delete("3/g/custom/remove_tags")
+                .query("tags", tags.join(","))
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Filtered out gallery add
+    ///
+    /// Filter out a tag from the user's galleries.
+    async fn add_filtered_tag(&self, tag: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post("3/g/block_tag")
+                .form([("tag", tag)])
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Filtered out gallery remove
+    ///
+    /// Remove a filtered out tag.
+    async fn remove_filtered_tag(&self, tag: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post("3/g/unblock_tag")
+                .form([("tag", tag)])
+                .authenticated(),
+        )
+        .await
+    }
+}
+
+impl CustomGalleryRegisteredClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::{BasicClient, GallerySort, Window},
+        endpoints::{
+            authorization::AuthenticationRegisteredClient,
+            custom_gallery::CustomGalleryRegisteredClient,
+        },
+    };
+    use imgurs_model::model::authorization::{AccessToken, ClientID, ClientSecret, RefreshToken};
+    use std::{convert::TryFrom, env, error::Error};
+    use time::OffsetDateTime;
+
+    #[tokio::test]
+    async fn test_get_custom_gallery() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client
+            .get_custom_gallery(GallerySort::Viral, Window::Week, 0)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+}
@@ -3,25 +3,352 @@
 use async_trait::async_trait;
 
 use imgurs_model::{
+    error::ErrorMessage,
     model::{
+        account::UserFollow,
+        account_settings::AccountSettings,
         album::AlbumID,
+        basic::{Basic, Data},
+        comment::{Comment, CreatedComment},
+        common::{FavoriteStatus, TagVote, Vote},
+        custom_gallery::CustomGalleryItem,
         gallery_album::GalleryAlbum,
         gallery_image::{GalleryImage, GalleryImageID},
-        gallery_tags::GalleryTags,
+        gallery_search::GallerySearchQuery,
+        gallery_tags::{GalleryItemTags, GalleryTags, Tag},
+        gallery_votes::GalleryVotes,
+        image::ImageID,
+        topic::{Topic, TopicID},
     },
-    utilities::pretty_json,
 };
 
 use crate::{
-    client::{AuthenticatedClient, BasicClient},
-    error::ClientError,
+    client::{
+        AuthenticatedClient, BasicClient, CommentSort, GallerySort, SearchSort, Section,
+        SubredditSort, TagSort, Window,
+    },
+    endpoint::Endpoint,
+    error::{ClientError, ErrorContext},
     response::Response,
     traits::{Client, RegisteredClient},
 };
 
+/// Gallery listing filters
+///
+/// Unset filters are not sent, leaving the API defaults.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct GalleryListingOptions {
+    /// Show or hide viral images from the `User` section, defaults to true
+    pub show_viral: Option<bool>,
+    /// Show or hide mature (nsfw) images in the response section, defaults to false
+    pub mature: Option<bool>,
+    /// Include image metadata for gallery posts which are albums, defaults to false
+    pub album_previews: Option<bool>,
+}
+
+impl GalleryListingOptions {
+    /// Encode the options to the respective query parameters, skipping unset values
+    pub fn to_query_parameters(&self) -> Vec<(&str, bool)> {
+        [
+            ("showViral", self.show_viral),
+            ("mature", self.mature),
+            ("album_previews", self.album_previews),
+        ]
+        .iter()
+        .filter_map(|(k, v)| v.map(|v| (*k, v)))
+        .collect()
+    }
+}
+
+/// Gallery submission details
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GalleryShare {
+    /// The title of the gallery post
+    pub title: String,
+    /// The name of the topic to post the content in
+    pub topic: Option<String>,
+    /// Accept the gallery terms on behalf of the user
+    pub accept_terms: bool,
+    /// Whether the content is mature (nsfw)
+    pub mature: Option<bool>,
+    /// Tags of the gallery post
+    pub tags: Vec<String>,
+}
+
+impl GalleryShare {
+    /// `GalleryShare` constructor, with the required title
+    pub fn new<T: Into<String>>(title: T) -> Self {
+        GalleryShare {
+            title: title.into(),
+            topic: None,
+            accept_terms: false,
+            mature: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Check the gallery terms are accepted, either explicitly or in the account settings
+    ///
+    /// Without account settings the check is left to the API.
+    pub fn check_terms(&self, settings: Option<&AccountSettings>) -> Result<(), ClientError> {
+        match settings {
+            Some(settings) if !self.accept_terms && !settings.accepted_gallery_terms() => {
+                Err(ClientError::GalleryTermsNotAccepted { context: None })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Encode the details to the respective form parameters, skipping unset values
+    pub fn to_form_parameters(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("title", self.title.clone())];
+        if let Some(topic) = &self.topic {
+            params.push(("topic", topic.clone()));
+        }
+        if self.accept_terms {
+            params.push(("terms", "1".to_string()));
+        }
+        if let Some(mature) = self.mature {
+            params.push(("mature", (mature as u8).to_string()));
+        }
+        if !self.tags.is_empty() {
+            params.push(("tags", self.tags.join(",")));
+        }
+        params
+    }
+}
+
+/// Whether a submission error is the rejection for the gallery terms
+///
+/// The API documents the rejection of submissions by users that have not accepted the terms,
+/// but not its exact message: any 400 error mentioning the acceptance of the terms matches.
+fn is_gallery_terms_error(status: u16, message: &ErrorMessage) -> bool {
+    let message = message.0.to_lowercase();
+    status == 400 && message.contains("terms") && message.contains("accept")
+}
+
+/// Map the gallery terms rejection of a submission to its own error
+///
+/// Any other response, including other errors, is returned unchanged.
+fn map_gallery_terms_error(res: Response<bool>) -> Result<Response<bool>, ClientError> {
+    let rate_limit = res.rate_limit();
+    let Response { content, headers } = res;
+    match content.data {
+        Data::Error {
+            error,
+            request,
+            method,
+        } if is_gallery_terms_error(content.status, &error) => {
+            Err(ClientError::GalleryTermsNotAccepted {
+                context: Some(Box::new(ErrorContext {
+                    status: content.status,
+                    message: error,
+                    request,
+                    method,
+                    rate_limit,
+                })),
+            })
+        }
+        data => Ok(Response {
+            content: Basic {
+                data,
+                success: content.success,
+                status: content.status,
+            },
+            headers,
+        }),
+    }
+}
+
 /// Gallery API client
 #[async_trait]
 pub trait GalleryClient: Client {
+    /// Gallery
+    ///
+    /// Returns the images and albums in the gallery.
+    /// `window` is only used with the `Top` section or sort.
+    async fn get_gallery(
+        &self,
+        section: Section,
+        sort: GallerySort,
+        window: Window,
+        page: u64,
+        options: GalleryListingOptions,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(
+            Endpoint::get(format!(
+                "3/gallery/{}/{}/{}/{}",
+                section, sort, window, page
+            ))
+            .query_pairs(options.to_query_parameters()),
+        )
+        .await
+    }
+
+    /// Gallery search
+    ///
+    /// Search the gallery with a given query.
+    /// `window` is only used with the `Top` sort.
+    async fn search_gallery(
+        &self,
+        query: &GallerySearchQuery,
+        sort: SearchSort,
+        window: Window,
+        page: u64,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(
+            Endpoint::get(format!("3/gallery/search/{}/{}/{}", sort, window, page))
+                .query_pairs(query.to_query_parameters()),
+        )
+        .await
+    }
+
+    /// Gallery votes
+    ///
+    /// Get the vote information about an image or album in the gallery.
+    async fn get_gallery_votes(
+        &self,
+        gallery_id: &str,
+    ) -> Result<Response<GalleryVotes>, ClientError> {
+        self.execute(Endpoint::get(format!("3/gallery/{}/votes", gallery_id)))
+            .await
+    }
+
+    /// Gallery comments
+    ///
+    /// Get the comments on an image or album in the gallery, with their replies.
+    async fn get_gallery_comments(
+        &self,
+        gallery_id: &str,
+        sort: CommentSort,
+    ) -> Result<Response<Vec<Comment>>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/gallery/{}/comments/{}",
+            gallery_id, sort
+        )))
+        .await
+    }
+
+    /// Gallery comment count
+    ///
+    /// The number of comments on an image or album in the gallery.
+    async fn get_gallery_comment_count(
+        &self,
+        gallery_id: &str,
+    ) -> Result<Response<u64>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/gallery/{}/comments/count",
+            gallery_id
+        )))
+        .await
+    }
+
+    /// Subreddit galleries
+    ///
+    /// View gallery images for a subreddit.
+    /// `window` is only used with the `Top` sort.
+    async fn get_subreddit_gallery(
+        &self,
+        subreddit: &str,
+        sort: SubredditSort,
+        window: Window,
+        page: u64,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/gallery/r/{}/{}/{}/{}",
+            subreddit, sort, window, page
+        )))
+        .await
+    }
+
+    /// Subreddit image
+    ///
+    /// View a single image in the subreddit.
+    async fn get_subreddit_image(
+        &self,
+        subreddit: &str,
+        gallery_image_id: &GalleryImageID,
+    ) -> Result<Response<GalleryImage>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/gallery/r/{}/{}",
+            subreddit, gallery_image_id
+        )))
+        .await
+    }
+
+    /// Tag gallery
+    ///
+    /// View the tag information and the gallery items with the tag, contained in its `items`.
+    /// `window` is only used with the `Top` sort.
+    async fn get_tag_gallery(
+        &self,
+        tag: &str,
+        sort: TagSort,
+        window: Window,
+        page: u64,
+    ) -> Result<Response<Tag>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/gallery/t/{}/{}/{}/{}",
+            tag, sort, window, page
+        )))
+        .await
+    }
+
+    /// Gallery tag info
+    ///
+    /// View the information about a tag, without its gallery items.
+    async fn get_tag_info(&self, tag: &str) -> Result<Response<Tag>, ClientError> {
+        self.execute(Endpoint::get(format!("3/gallery/tag_info/{}", tag)))
+            .await
+    }
+
+    /// Gallery item tags
+    ///
+    /// View tags for an image or album in the gallery.
+    async fn get_gallery_item_tags(
+        &self,
+        gallery_id: &str,
+    ) -> Result<Response<GalleryItemTags>, ClientError> {
+        self.execute(Endpoint::get(format!("3/gallery/{}/tags", gallery_id)))
+            .await
+    }
+
+    /// Default topics
+    ///
+    /// Get the list of default topics.
+    async fn get_default_topics(&self) -> Result<Response<Vec<Topic>>, ClientError> {
+        self.execute(Endpoint::get("3/topics/defaults")).await
+    }
+
+    /// Gallery topic
+    ///
+    /// View gallery items for a topic.
+    /// `window` is only used with the `Top` sort.
+    async fn get_topic_gallery(
+        &self,
+        topic_id: TopicID,
+        sort: TagSort,
+        window: Window,
+        page: u64,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(Endpoint::get(format!(
+            "3/topics/{}/{}/{}/{}",
+            topic_id, sort, window, page
+        )))
+        .await
+    }
+
+    /// Random gallery
+    ///
+    /// Returns a random set of gallery images.
+    async fn get_random_gallery(
+        &self,
+        page: u64,
+    ) -> Result<Response<Vec<CustomGalleryItem>>, ClientError> {
+        self.execute(Endpoint::get(format!("3/gallery/random/random/{}", page)))
+            .await
+    }
+
     /// Gallery album
     ///
     /// Get additional information about an album in the gallery.
@@ -29,20 +356,8 @@ pub trait GalleryClient: Client {
         &self,
         album_id: &AlbumID,
     ) -> Result<Response<GalleryAlbum>, ClientError> {
-        let res = self
-            .get_client()
-            .get(&format!(
-                "https://api.imgur.com/3/gallery/album/{}",
-                album_id
-            ))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
-
-        let headers = res.headers().clone();
-        let content = res.json().await?;
-
-        Ok(Response { content, headers })
+        self.execute(Endpoint::get(format!("3/gallery/album/{}", album_id)))
+            .await
     }
 
     /// Gallery image
@@ -52,58 +367,181 @@ pub trait GalleryClient: Client {
         &self,
         gallery_image_id: &GalleryImageID,
     ) -> Result<Response<GalleryImage>, ClientError> {
-        let res = self
-            .get_client()
-            .get(&format!(
-                "https://api.imgur.com/3/gallery/image/{id}",
-                id = gallery_image_id
+        self.execute(Endpoint::get(format!(
+            "3/gallery/image/{id}",
+            id = gallery_image_id
+        )))
+        .await
+    }
+
+    /// Gallery image
+    ///
+    /// Get additional information about an image in the gallery.
+    async fn get_gallery_tags(&self) -> Result<Response<GalleryTags>, ClientError> {
+        self.execute(Endpoint::get("3/tags")).await
+    }
+}
+
+/// Registered client gallery API client
+#[async_trait]
+pub trait GalleryRegisteredClient: GalleryClient + RegisteredClient {
+    /// Gallery vote
+    ///
+    /// Vote for an image or album in the gallery. `Vote::Veto` removes a previous vote.
+    async fn vote_gallery_item(
+        &self,
+        gallery_id: &str,
+        vote: Vote,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/gallery/{}/vote/{}", gallery_id, vote)).authenticated(),
+        )
+        .await
+    }
+
+    /// Gallery comment creation
+    ///
+    /// Create a comment on an image or album in the gallery.
+    async fn create_gallery_comment(
+        &self,
+        gallery_id: &str,
+        comment: &str,
+    ) -> Result<Response<CreatedComment>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/gallery/{}/comment", gallery_id))
+                .form([("comment", comment)])
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Gallery tag voting
+    ///
+    /// Vote for a tag on an image or album in the gallery.
+    async fn vote_gallery_item_tag(
+        &self,
+        gallery_id: &str,
+        tag: &str,
+        vote: TagVote,
+    ) -> Result<Response<bool>, ClientError> {
+        self.execute(
+            Endpoint::post(format!(
+                "3/gallery/{}/vote/tag/{}/{}",
+                gallery_id, tag, vote
             ))
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
+            .authenticated(),
+        )
+        .await
+    }
 
-        let headers = res.headers().clone();
-        println!("{:#?}", headers);
+    /// Follow tag
+    ///
+    /// Follows the tag for the current user.
+    async fn follow_tag(&self, tag: &str) -> Result<Response<UserFollow>, ClientError> {
+        self.execute(Endpoint::post(format!("3/account/me/follow/tag/{}", tag)).authenticated())
+            .await
+    }
 
-        let text = res.text().await?;
-        println!("{}", text);
-        println!("{}", pretty_json(&text)?);
+    /// Unfollow tag
+    ///
+    /// Unfollows the tag for the current user.
+    async fn unfollow_tag(&self, tag: &str) -> Result<Response<UserFollow>, ClientError> {
+        self.execute(Endpoint::delete(format!("3/account/me/follow/tag/{}", tag)).authenticated())
+            .await
+    }
 
-        let content = serde_json::from_str(&text)?;
-        // let content = res.json().await?;
+    /// Followed default tags
+    ///
+    /// Get the tags followed by the current user among the default ones listed by
+    /// `get_gallery_tags`. This is a partial view: followed tags missing from the default
+    /// listing are not returned.
+    async fn get_followed_default_tags(&self) -> Result<Response<Vec<Tag>>, ClientError> {
+        let Response { content, headers } = self.get_gallery_tags().await?;
+        let content = content.map(|tags| tags.followed_tags().cloned().collect());
 
         Ok(Response { content, headers })
     }
 
-    /// Gallery image
+    /// Share an image to the gallery
     ///
-    /// Get additional information about an image in the gallery.
-    async fn get_gallery_tags(&self) -> Result<Response<GalleryTags>, ClientError> {
-        let res = self
-            .get_client()
-            .get("https://api.imgur.com/3/tags")
-            .headers(self.get_headers()?)
-            .send()
-            .await?;
+    /// Share an image owned by the user to the gallery.
+    /// If `account_settings` are available the gallery terms acceptance is checked before
+    /// sending the request.
+    async fn share_image(
+        &self,
+        image_id: &ImageID,
+        share: &GalleryShare,
+        account_settings: Option<&AccountSettings>,
+    ) -> Result<Response<bool>, ClientError> {
+        share.check_terms(account_settings)?;
+
+        self.execute(
+            Endpoint::post(format!("3/gallery/image/{}", image_id))
+                .form(share.to_form_parameters())
+                .authenticated(),
+        )
+        .await
+        .and_then(map_gallery_terms_error)
+    }
+
+    /// Share an album to the gallery
+    ///
+    /// Share an album owned by the user to the gallery.
+    /// If `account_settings` are available the gallery terms acceptance is checked before
+    /// sending the request.
+    async fn share_album(
+        &self,
+        album_id: &AlbumID,
+        share: &GalleryShare,
+        account_settings: Option<&AccountSettings>,
+    ) -> Result<Response<bool>, ClientError> {
+        share.check_terms(account_settings)?;
 
-        let headers = res.headers().clone();
-        println!("{:#?}", headers);
+        self.execute(
+            Endpoint::post(format!("3/gallery/album/{}", album_id))
+                .form(share.to_form_parameters())
+                .authenticated(),
+        )
+        .await
+        .and_then(map_gallery_terms_error)
+    }
 
-        let text = res.text().await?;
-        println!("{}", text);
-        println!("{}", pretty_json(&text)?);
+    /// Remove from gallery
+    ///
+    /// Remove an image or album owned by the user from the gallery.
+    async fn remove_from_gallery(&self, gallery_id: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::delete(format!("3/gallery/{}", gallery_id)).authenticated())
+            .await
+    }
 
-        let content = serde_json::from_str(&text)?;
-        // let content = res.json().await?;
+    /// Favorite a gallery image
+    ///
+    /// Favorites a gallery image, or removes it from the favorites if already present.
+    async fn favorite_gallery_image(
+        &self,
+        gallery_image_id: &GalleryImageID,
+    ) -> Result<Response<FavoriteStatus>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/gallery/image/{}/favorite", gallery_image_id))
+                .authenticated(),
+        )
+        .await
+    }
 
-        Ok(Response { content, headers })
+    /// Favorite a gallery album
+    ///
+    /// Favorites a gallery album, or removes it from the favorites if already present.
+    async fn favorite_gallery_album(
+        &self,
+        album_id: &AlbumID,
+    ) -> Result<Response<FavoriteStatus>, ClientError> {
+        self.execute(
+            Endpoint::post(format!("3/gallery/album/{}/favorite", album_id)).authenticated(),
+        )
+        .await
     }
 }
 
-/// Registered client gallery API client
-#[async_trait]
-pub trait GalleryRegisteredClient: RegisteredClient {}
-
 impl GalleryClient for BasicClient {}
 
 impl GalleryClient for AuthenticatedClient {}
@@ -112,10 +550,252 @@ impl GalleryRegisteredClient for AuthenticatedClient {}
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::BasicClient, endpoints::gallery::GalleryClient};
-    use imgurs_model::model::authorization::{ClientID, ClientSecret};
+    use crate::{
+        client::{
+            BasicClient, CommentSort, GallerySort, SearchSort, Section, SubredditSort, TagSort,
+            Window,
+        },
+        endpoints::gallery::{
+            map_gallery_terms_error, GalleryClient, GalleryListingOptions, GalleryShare,
+        },
+        error::ClientError,
+        response::Response,
+    };
+    use imgurs_model::{
+        error::ErrorMessage,
+        model::{
+            account_settings::AccountSettings,
+            authorization::{ClientID, ClientSecret},
+            basic::{Basic, Data, Method},
+            gallery_search::{GallerySearchQuery, SearchFileType},
+        },
+    };
+
+    use reqwest::header::HeaderMap;
     use std::{convert::TryFrom, env, error::Error};
 
+    #[test]
+    fn test_gallery_share_terms_preflight() -> Result<(), Box<dyn Error>> {
+        let settings = serde_json::from_str::<Basic<AccountSettings>>(
+            r#"{"data":{"email":"josh@imgur.com","public_images":false,"album_privacy":"secret","pro_expiration":false,"accepted_gallery_terms":false,"active_emails":[],"messaging_enabled":true,"blocked_users":[],"show_mature":false,"first_party":true},"success":true,"status":200}"#,
+        )?
+        .result()?;
+        let share = GalleryShare {
+            tags: vec!["cats".to_string(), "dogs".to_string()],
+            ..GalleryShare::new("Imgurs")
+        };
+
+        assert!(share.check_terms(None).is_ok());
+        assert!(matches!(
+            share.check_terms(Some(&settings)),
+            Err(ClientError::GalleryTermsNotAccepted { context: None })
+        ));
+
+        let share = GalleryShare {
+            accept_terms: true,
+            ..share
+        };
+        assert!(share.check_terms(Some(&settings)).is_ok());
+        assert!(share
+            .to_form_parameters()
+            .contains(&("terms", "1".to_string())));
+        assert!(share
+            .to_form_parameters()
+            .contains(&("tags", "cats,dogs".to_string())));
+
+        Ok(())
+    }
+
+    fn error_response(status: u16, message: &str) -> Response<bool> {
+        Response {
+            content: Basic {
+                data: Data::Error {
+                    error: ErrorMessage::new(message),
+                    request: "/3/gallery/image/MDCEW6Q".to_owned(),
+                    method: Method::POST,
+                },
+                success: false,
+                status,
+            },
+            headers: HeaderMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_gallery_terms_error_mapping() {
+        for message in [
+            "You must accept the terms to submit to the gallery",
+            "User has not accepted the gallery terms",
+            "Please accept our Terms of Service before sharing",
+        ] {
+            match map_gallery_terms_error(error_response(400, message)) {
+                Err(ClientError::GalleryTermsNotAccepted {
+                    context: Some(context),
+                }) => {
+                    assert_eq!(context.status, 400);
+                    assert_eq!(context.request, "/3/gallery/image/MDCEW6Q");
+                    assert_eq!(context.message.0, message);
+                }
+                res => panic!("Unexpected result for {:?}: {:?}", message, res),
+            }
+        }
+    }
+
+    #[test]
+    fn test_gallery_other_errors_unchanged() -> Result<(), Box<dyn Error>> {
+        for (status, message) in [
+            (400, "Invalid search terms"),
+            (400, "Title is required"),
+            (500, "Could not accept the terms"),
+        ] {
+            let res = error_response(status, message);
+            assert_eq!(map_gallery_terms_error(res.clone())?, res);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_gallery() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let options = GalleryListingOptions {
+            mature: Some(false),
+            album_previews: Some(true),
+            ..GalleryListingOptions::default()
+        };
+        let res = client
+            .get_gallery(Section::Top, GallerySort::Top, Window::Week, 0, options)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_gallery() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let query = GallerySearchQuery::new()
+            .all("cats")
+            .file_type(SearchFileType::Anigif);
+        let res = client
+            .search_gallery(&query, SearchSort::Top, Window::Month, 0)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_gallery_votes() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_gallery_votes("HvCcoNA")
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_gallery_comments() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_gallery_comments("HvCcoNA", CommentSort::Top)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_subreddit_gallery() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_subreddit_gallery("pics", SubredditSort::Time, Window::Day, 0)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_tag_gallery() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_tag_gallery("cats", TagSort::Viral, Window::Week, 0)
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+        assert!(res.items.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_default_topics() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client.get_default_topics().await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_gallery_item_tags() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_gallery_item_tags("HvCcoNA")
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_deserialize_gallery_album_remote() -> Result<(), Box<dyn Error>> {
         let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
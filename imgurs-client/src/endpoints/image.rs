@@ -0,0 +1,209 @@
+//! Image API implementation
+
+use async_trait::async_trait;
+use reqwest::multipart::{Form, Part};
+use url::Url;
+
+use imgurs_model::model::{
+    common::FavoriteStatus,
+    image::{Image, ImageID},
+};
+
+use crate::{
+    client::{AuthenticatedClient, BasicClient},
+    endpoint::Endpoint,
+    error::ClientError,
+    response::Response,
+    traits::{Client, RegisteredClient},
+};
+
+/// Source of the content of an image upload
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ImageSource {
+    /// Binary content of the image file
+    File(Vec<u8>),
+    /// URL of a remote image
+    Url(Url),
+    /// Base64 encoded content of the image file
+    Base64(String),
+}
+
+impl ImageSource {
+    /// Encode the source type to the respective form parameter value
+    pub fn to_form_parameter(&self) -> &str {
+        match self {
+            ImageSource::File(_) => "file",
+            ImageSource::Url(_) => "url",
+            ImageSource::Base64(_) => "base64",
+        }
+    }
+}
+
+/// Optional details of an image upload
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ImageUpload {
+    /// The id of the album to add the image to.
+    ///
+    /// For anonymous albums this must be the album deletehash.
+    pub album: Option<String>,
+    /// The name of the file
+    pub name: Option<String>,
+    /// The title of the image
+    pub title: Option<String>,
+    /// The description of the image
+    pub description: Option<String>,
+}
+
+/// Image API client
+#[async_trait]
+pub trait ImageClient: Client {
+    /// Image
+    ///
+    /// Get information about an image.
+    async fn get_image(&self, image_id: &ImageID) -> Result<Response<Image>, ClientError> {
+        self.execute(Endpoint::get(format!("3/image/{}", image_id)))
+            .await
+    }
+
+    /// Image upload
+    ///
+    /// Upload a new image.
+    /// Anonymous uploads can only be added to an anonymous album, using its deletehash.
+    async fn upload_image(
+        &self,
+        source: ImageSource,
+        details: ImageUpload,
+    ) -> Result<Response<Image>, ClientError> {
+        let mut form = Form::new().text("type", source.to_form_parameter().to_owned());
+        form = match source {
+            ImageSource::File(bytes) => {
+                let part = Part::bytes(bytes);
+                let part = match &details.name {
+                    Some(name) => part.file_name(name.clone()),
+                    None => part,
+                };
+                form.part("image", part)
+            }
+            ImageSource::Url(url) => form.text("image", url.to_string()),
+            ImageSource::Base64(data) => form.text("image", data),
+        };
+        for (key, value) in [
+            ("album", details.album),
+            ("name", details.name),
+            ("title", details.title),
+            ("description", details.description),
+        ] {
+            if let Some(value) = value {
+                form = form.text(key, value);
+            }
+        }
+
+        self.execute(Endpoint::post("3/image").multipart(form))
+            .await
+    }
+
+    /// Update image information
+    ///
+    /// Updates the title or description of an image.
+    /// `image_hash` is the image id for images owned by the user, the deletehash for anonymous ones.
+    async fn update_image(
+        &self,
+        image_hash: &str,
+        title: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<Response<bool>, ClientError> {
+        let params: Vec<(&str, &str)> = [("title", title), ("description", description)]
+            .iter()
+            .filter_map(|(k, v)| v.map(|v| (*k, v)))
+            .collect();
+
+        self.execute(Endpoint::post(format!("3/image/{}", image_hash)).form(params))
+            .await
+    }
+
+    /// Image deletion
+    ///
+    /// Deletes an image.
+    /// `image_hash` is the image id for images owned by the user, the deletehash for anonymous ones.
+    async fn delete_image(&self, image_hash: &str) -> Result<Response<bool>, ClientError> {
+        self.execute(Endpoint::delete(format!("3/image/{}", image_hash)))
+            .await
+    }
+}
+
+/// Registered client image API client
+#[async_trait]
+pub trait ImageRegisteredClient: ImageClient + RegisteredClient {
+    /// Favorite an image
+    ///
+    /// Favorites an image with the given ID, or removes it from the favorites if already present.
+    async fn favorite_image(
+        &self,
+        image_id: &ImageID,
+    ) -> Result<Response<FavoriteStatus>, ClientError> {
+        self.execute(Endpoint::post(format!("3/image/{}/favorite", image_id)).authenticated())
+            .await
+    }
+}
+
+impl ImageClient for BasicClient {}
+
+impl ImageClient for AuthenticatedClient {}
+
+impl ImageRegisteredClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::BasicClient,
+        endpoints::image::{ImageClient, ImageSource, ImageUpload},
+    };
+    use imgurs_model::model::authorization::{ClientID, ClientSecret};
+    use std::{convert::TryFrom, env, error::Error};
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_get_image() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let res = client
+            .get_image(&"MDCEW6Q".into())
+            .await?
+            .content
+            .result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[tokio::test]
+    async fn test_upload_and_delete_image() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let client = BasicClient::new(client_id, client_secret)?;
+
+        let source = ImageSource::Url(Url::parse("https://i.imgur.com/MDCEW6Q.png")?);
+        let details = ImageUpload {
+            title: Some("Imgurs test upload".to_string()),
+            ..ImageUpload::default()
+        };
+        let res = client
+            .upload_image(source, details)
+            .await?
+            .content
+            .result()?;
+        println!("{:#?}", res);
+
+        let delete_hash = res
+            .deletehash
+            .expect("Anonymous uploads return a deletehash");
+        let res = client.delete_image(&delete_hash).await?.content.result()?;
+        assert!(res);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+//! Notification API implementation
+
+use async_trait::async_trait;
+
+use imgurs_model::model::notification::{
+    Notification, NotificationContent, NotificationID, Notifications,
+};
+
+use crate::{
+    client::AuthenticatedClient, endpoint::Endpoint, error::ClientError, response::Response,
+    traits::RegisteredClient,
+};
+
+/// Registered client notification API client
+#[async_trait]
+pub trait NotificationRegisteredClient: RegisteredClient {
+    /// Notifications
+    ///
+    /// Returns all of the reply notifications for the user.
+    /// If `new_only` is set, only the unviewed notifications are returned.
+    async fn get_notifications(
+        &self,
+        new_only: bool,
+    ) -> Result<Response<Notifications>, ClientError> {
+        self.execute(
+            Endpoint::get("3/notification")
+                .query("new", new_only)
+                .authenticated(),
+        )
+        .await
+    }
+
+    /// Notification
+    ///
+    /// Returns the data about a specific notification.
+    async fn get_notification(
+        &self,
+        notification_id: NotificationID,
+    ) -> Result<Response<Notification<NotificationContent>>, ClientError> {
+        self.execute(Endpoint::get(format!("3/notification/{}", notification_id)).authenticated())
+            .await
+    }
+
+    /// Mark notifications as viewed
+    ///
+    /// Marks the given notifications as viewed.
+    async fn mark_notifications_viewed(
+        &self,
+        notification_ids: &[NotificationID],
+    ) -> Result<Response<bool>, ClientError> {
+        let ids = notification_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.execute(
+            Endpoint::post("3/notification")
+                .form([("ids", ids)])
+                .authenticated(),
+        )
+        .await
+    }
+}
+
+impl NotificationRegisteredClient for AuthenticatedClient {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::BasicClient,
+        endpoints::{
+            authorization::AuthenticationRegisteredClient,
+            notification::NotificationRegisteredClient,
+        },
+    };
+    use imgurs_model::model::authorization::{AccessToken, ClientID, ClientSecret, RefreshToken};
+    use std::{convert::TryFrom, env, error::Error};
+    use time::OffsetDateTime;
+
+    #[tokio::test]
+    async fn test_get_notifications() -> Result<(), Box<dyn Error>> {
+        let client_id = ClientID::try_from(env::var("CLIENT_ID")?)?;
+        let client_secret = ClientSecret::try_from(env::var("CLIENT_SECRET")?)?;
+        let access_token = AccessToken::try_from(env::var("ACCESS_TOKEN")?)?;
+        let refresh_token = RefreshToken::try_from(env::var("REFRESH_TOKEN")?)?;
+        let client = BasicClient::new(client_id, client_secret)?
+            .with_tokens(access_token, refresh_token, OffsetDateTime::now_utc())?
+            .with_fresh_tokens()
+            .await?;
+
+        let res = client.get_notifications(false).await?.content.result()?;
+
+        println!("{:#?}", res);
+
+        Ok(())
+    }
+}
@@ -2,9 +2,59 @@
 
 use std::{error::Error, fmt};
 
-use reqwest::header::{InvalidHeaderName, InvalidHeaderValue, ToStrError};
+use reqwest::header::{HeaderName, InvalidHeaderName, InvalidHeaderValue, ToStrError};
+use time::OffsetDateTime;
 
-use imgurs_model::error::ErrorMessage;
+use imgurs_model::{error::ErrorMessage, model::basic::Method};
+
+use crate::response::RateLimit;
+
+/// Context of an error returned by the API
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorContext {
+    /// HTTP status
+    pub status: u16,
+    /// Error message
+    pub message: ErrorMessage,
+    /// Requested path
+    pub request: String,
+    /// Method used in the request
+    pub method: Method,
+    /// Rate limit information reported with the error
+    pub rate_limit: RateLimit,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} {}: {}",
+            self.status, self.method, self.request, self.message
+        )
+    }
+}
+
+/// Context of a response whose body could not be parsed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidResponseContext {
+    /// HTTP status
+    pub status: u16,
+    /// Requested path
+    pub request: String,
+    /// Method used in the request
+    pub method: reqwest::Method,
+    /// Rate limit information reported with the response
+    pub rate_limit: RateLimit,
+    /// Beginning of the response body, only kept for API error statuses,
+    /// as the OAuth and successful responses may contain the user tokens
+    pub body_snippet: Option<String>,
+}
+
+impl fmt::Display for InvalidResponseContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.status, self.method, self.request)
+    }
+}
 
 /// Client error wrapper
 #[derive(Debug)]
@@ -21,8 +71,79 @@ pub enum ClientError {
     InvalidHeaderName(InvalidHeaderName),
     /// Invalid value name error
     InvalidHeaderValue(InvalidHeaderValue),
+    /// Header set by the client, that cannot be overridden
+    ReservedHeader(HeaderName),
     /// Error message
     ErrorMessage(ErrorMessage),
+    /// The gallery terms must be accepted before sharing content to the gallery
+    GalleryTermsNotAccepted {
+        /// Error returned by the API, `None` if the request was rejected before being sent
+        context: Option<Box<ErrorContext>>,
+    },
+    /// The endpoint requires an authenticated user, the request was not sent
+    AuthenticationRequired,
+    /// The rate limit budget is exhausted
+    RateLimited {
+        /// Time of the budget reset, if known
+        reset_at: Option<OffsetDateTime>,
+        /// Error returned by the API, `None` if the request was held back by the governor
+        context: Option<Box<ErrorContext>>,
+    },
+    /// The request requires a valid authentication (401)
+    Unauthorized(Box<ErrorContext>),
+    /// The request is not allowed for the user (403)
+    Forbidden(Box<ErrorContext>),
+    /// The requested resource does not exist (404)
+    NotFound(Box<ErrorContext>),
+    /// The API failed to handle the request (5xx)
+    ServerError(Box<ErrorContext>),
+    /// Any other error returned by the API
+    ApiError(Box<ErrorContext>),
+    /// The response body could not be parsed
+    InvalidResponse {
+        /// Response the body belongs to
+        context: Box<InvalidResponseContext>,
+        /// Parsing error of the body
+        source: serde_json::Error,
+    },
+}
+
+impl ClientError {
+    /// Build the error matching the status of an error returned by the API
+    pub fn from_context(context: ErrorContext) -> Self {
+        match context.status {
+            401 => ClientError::Unauthorized(Box::new(context)),
+            403 => ClientError::Forbidden(Box::new(context)),
+            404 => ClientError::NotFound(Box::new(context)),
+            429 => ClientError::RateLimited {
+                reset_at: context.rate_limit.reset_at(),
+                context: Some(Box::new(context)),
+            },
+            500..=599 => ClientError::ServerError(Box::new(context)),
+            _ => ClientError::ApiError(Box::new(context)),
+        }
+    }
+
+    /// HTTP status of the response, if the error comes from one
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClientError::RequestError(e) => e.status().map(|status| status.as_u16()),
+            ClientError::RateLimited {
+                context: Some(context),
+                ..
+            }
+            | ClientError::GalleryTermsNotAccepted {
+                context: Some(context),
+            }
+            | ClientError::Unauthorized(context)
+            | ClientError::Forbidden(context)
+            | ClientError::NotFound(context)
+            | ClientError::ServerError(context)
+            | ClientError::ApiError(context) => Some(context.status),
+            ClientError::InvalidResponse { context, .. } => Some(context.status),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ClientError {
@@ -34,12 +155,43 @@ impl fmt::Display for ClientError {
             ClientError::ToStrError(e) => e.fmt(f),
             ClientError::InvalidHeaderName(e) => e.fmt(f),
             ClientError::InvalidHeaderValue(e) => e.fmt(f),
+            ClientError::ReservedHeader(name) => {
+                write!(f, "The {} header is set by the client", name)
+            }
             ClientError::ErrorMessage(e) => e.fmt(f),
+            ClientError::GalleryTermsNotAccepted { .. } => {
+                write!(f, "The gallery terms must be accepted to share content")
+            }
+            ClientError::AuthenticationRequired => {
+                write!(f, "The endpoint requires an authenticated user")
+            }
+            ClientError::RateLimited {
+                reset_at: Some(reset_at),
+                ..
+            } => write!(f, "Rate limit budget exhausted until {}", reset_at),
+            ClientError::RateLimited { reset_at: None, .. } => {
+                write!(f, "Rate limit budget exhausted")
+            }
+            ClientError::Unauthorized(context) => write!(f, "Unauthorized: {}", context),
+            ClientError::Forbidden(context) => write!(f, "Forbidden: {}", context),
+            ClientError::NotFound(context) => write!(f, "Not found: {}", context),
+            ClientError::ServerError(context) => write!(f, "Server error: {}", context),
+            ClientError::ApiError(context) => context.fmt(f),
+            ClientError::InvalidResponse { context, .. } => {
+                write!(f, "Invalid response: {}", context)
+            }
         }
     }
 }
 
-impl Error for ClientError {}
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::InvalidResponse { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
 
 impl From<url::ParseError> for ClientError {
     fn from(e: url::ParseError) -> Self {
@@ -0,0 +1,300 @@
+//! Rate limit governor implementation
+//!
+//! Imgur penalizes the clients that keep calling the API after exhausting their credits.
+//! The governor tracks the budget reported by each response and holds back the requests
+//! that would exceed it, either waiting for the reset or failing early.
+use std::{
+    convert::TryFrom,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use reqwest::Method;
+use time::{Duration, OffsetDateTime};
+
+use crate::{error::ClientError, response::RateLimit};
+
+/// Rate limit governor settings
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GovernorSettings {
+    /// Credits kept in reserve, requests are held back once the remaining credits reach it
+    pub reserve: u64,
+    /// POST requests kept in reserve, POST requests are held back once the remaining ones reach it
+    pub post_reserve: u64,
+    /// Longest time a request waits for a budget reset, before failing instead
+    pub max_delay: Duration,
+}
+
+impl Default for GovernorSettings {
+    fn default() -> Self {
+        GovernorSettings {
+            reserve: 10,
+            post_reserve: 1,
+            max_delay: Duration::minutes(1),
+        }
+    }
+}
+
+/// Period of the user and POST budgets, assumed when the API does not report their reset
+const HOURLY_BUDGET_PERIOD: Duration = Duration::hours(1);
+/// Period of the client budget, whose reset is never reported
+const CLIENT_BUDGET_PERIOD: Duration = Duration::days(1);
+
+/// Last known state of the budgets
+///
+/// Every recorded budget has a reset time, either reported by the API or assumed from its period,
+/// so a budget held back by the governor is forgotten even if no later response refreshes it.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Budget {
+    user_remaining: Option<u64>,
+    user_reset: Option<OffsetDateTime>,
+    client_remaining: Option<u64>,
+    client_reset: Option<OffsetDateTime>,
+    post_remaining: Option<u64>,
+    post_reset: Option<OffsetDateTime>,
+}
+
+impl Budget {
+    /// Forget the budgets whose reset time has passed
+    fn expire(&mut self, now: OffsetDateTime) {
+        if self.user_reset.is_some_and(|reset| reset <= now) {
+            self.user_remaining = None;
+            self.user_reset = None;
+        }
+        if self.client_reset.is_some_and(|reset| reset <= now) {
+            self.client_remaining = None;
+            self.client_reset = None;
+        }
+        if self.post_reset.is_some_and(|reset| reset <= now) {
+            self.post_remaining = None;
+            self.post_reset = None;
+        }
+    }
+
+    /// Spend the credits of a request ahead of its response
+    fn spend(&mut self, method: &Method) {
+        self.user_remaining = self.user_remaining.map(|r| r.saturating_sub(1));
+        self.client_remaining = self.client_remaining.map(|r| r.saturating_sub(1));
+        if method == Method::POST {
+            self.post_remaining = self.post_remaining.map(|r| r.saturating_sub(1));
+        }
+    }
+}
+
+/// Rate limit governor
+///
+/// Clones share the same budget, so a governor can be shared by multiple clients.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimitGovernor {
+    settings: GovernorSettings,
+    budget: Arc<Mutex<Budget>>,
+}
+
+impl RateLimitGovernor {
+    /// `RateLimitGovernor` constructor
+    pub fn new(settings: GovernorSettings) -> Self {
+        RateLimitGovernor {
+            settings,
+            budget: Arc::default(),
+        }
+    }
+
+    /// Get the governor settings
+    pub fn settings(&self) -> &GovernorSettings {
+        &self.settings
+    }
+
+    /// Record the budget reported by a response
+    pub fn record(&self, rate_limit: &RateLimit) {
+        let now = OffsetDateTime::now_utc();
+        let mut budget = self.budget.lock().unwrap_or_else(PoisonError::into_inner);
+        if rate_limit.user_remaining.is_some() {
+            budget.user_remaining = rate_limit.user_remaining;
+            budget.user_reset = Some(rate_limit.user_reset.unwrap_or(now + HOURLY_BUDGET_PERIOD));
+        }
+        if rate_limit.client_remaining.is_some() {
+            budget.client_remaining = rate_limit.client_remaining;
+            budget.client_reset = Some(now + CLIENT_BUDGET_PERIOD);
+        }
+        if rate_limit.post_remaining.is_some() {
+            budget.post_remaining = rate_limit.post_remaining;
+            budget.post_reset = Some(now + rate_limit.post_reset.unwrap_or(HOURLY_BUDGET_PERIOD));
+        }
+    }
+
+    /// Reserve the credits of a request
+    ///
+    /// Waits for the budget reset if it is within the maximum delay,
+    /// fails with `ClientError::RateLimited` otherwise.
+    pub async fn acquire(&self, method: &Method) -> Result<(), ClientError> {
+        while let Some(delay) = self.check(method, OffsetDateTime::now_utc())? {
+            let delay = std::time::Duration::try_from(delay).unwrap_or_default();
+            tokio::time::sleep(delay).await;
+        }
+        Ok(())
+    }
+
+    /// Reserve the credits of a request, or get the time to wait for the budget reset
+    fn check(&self, method: &Method, now: OffsetDateTime) -> Result<Option<Duration>, ClientError> {
+        let mut budget = self.budget.lock().unwrap_or_else(PoisonError::into_inner);
+        budget.expire(now);
+
+        let mut reset_at: Option<OffsetDateTime> = None;
+        let mut block = |reset: Option<OffsetDateTime>| {
+            reset_at = reset_at.max(reset.or(Some(now)));
+        };
+        if budget
+            .client_remaining
+            .is_some_and(|r| r <= self.settings.reserve)
+        {
+            block(budget.client_reset);
+        }
+        if budget
+            .user_remaining
+            .is_some_and(|r| r <= self.settings.reserve)
+        {
+            block(budget.user_reset);
+        }
+        if method == Method::POST
+            && budget
+                .post_remaining
+                .is_some_and(|r| r <= self.settings.post_reserve)
+        {
+            block(budget.post_reset);
+        }
+
+        match reset_at {
+            None => {
+                budget.spend(method);
+                Ok(None)
+            }
+            Some(reset_at) if reset_at - now <= self.settings.max_delay => Ok(Some(reset_at - now)),
+            reset_at => Err(ClientError::RateLimited {
+                reset_at,
+                context: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ClientError,
+        governor::{GovernorSettings, RateLimitGovernor},
+        response::RateLimit,
+    };
+    use reqwest::Method;
+    use time::{Duration, OffsetDateTime};
+
+    #[test]
+    fn test_governor_spends_budget() {
+        let governor = RateLimitGovernor::new(GovernorSettings {
+            reserve: 1,
+            ..GovernorSettings::default()
+        });
+        let now = OffsetDateTime::now_utc();
+        governor.record(&RateLimit {
+            user_remaining: Some(3),
+            user_reset: Some(now + Duration::hours(1)),
+            ..RateLimit::default()
+        });
+
+        assert!(matches!(governor.check(&Method::GET, now), Ok(None)));
+        assert!(matches!(governor.check(&Method::GET, now), Ok(None)));
+        assert!(matches!(
+            governor.check(&Method::GET, now),
+            Err(ClientError::RateLimited {
+                reset_at: Some(_),
+                context: None
+            })
+        ));
+        // The budget is forgotten once its reset time has passed
+        assert!(matches!(
+            governor.check(&Method::GET, now + Duration::hours(2)),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn test_governor_waits_for_close_reset() {
+        let governor = RateLimitGovernor::default();
+        let now = OffsetDateTime::now_utc();
+        governor.record(&RateLimit {
+            user_remaining: Some(0),
+            user_reset: Some(now + Duration::seconds(10)),
+            ..RateLimit::default()
+        });
+
+        match governor.check(&Method::GET, now) {
+            Ok(Some(delay)) => assert!(delay <= Duration::seconds(10)),
+            res => panic!("Unexpected check result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_governor_post_budget() {
+        let governor = RateLimitGovernor::default();
+        let now = OffsetDateTime::now_utc();
+        governor.record(&RateLimit {
+            user_remaining: Some(100),
+            client_remaining: Some(100),
+            post_remaining: Some(0),
+            post_reset: Some(Duration::hours(1)),
+            ..RateLimit::default()
+        });
+
+        assert!(matches!(governor.check(&Method::GET, now), Ok(None)));
+        assert!(matches!(
+            governor.check(&Method::POST, now),
+            Err(ClientError::RateLimited {
+                reset_at: Some(_),
+                context: None
+            })
+        ));
+    }
+
+    #[test]
+    fn test_governor_client_budget_recovers() {
+        let governor = RateLimitGovernor::default();
+        let now = OffsetDateTime::now_utc();
+        governor.record(&RateLimit {
+            client_remaining: Some(0),
+            ..RateLimit::default()
+        });
+
+        match governor.check(&Method::GET, now) {
+            Err(ClientError::RateLimited {
+                reset_at: Some(reset_at),
+                context: None,
+            }) => assert!(reset_at > now + Duration::hours(23)),
+            res => panic!("Unexpected check result: {:?}", res),
+        }
+        // The client budget resets daily, even though the API does not report it
+        assert!(matches!(
+            governor.check(&Method::GET, now + Duration::hours(25)),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn test_governor_user_budget_without_reset_recovers() {
+        let governor = RateLimitGovernor::default();
+        let now = OffsetDateTime::now_utc();
+        governor.record(&RateLimit {
+            user_remaining: Some(0),
+            ..RateLimit::default()
+        });
+
+        assert!(matches!(
+            governor.check(&Method::GET, now),
+            Err(ClientError::RateLimited {
+                reset_at: Some(_),
+                context: None
+            })
+        ));
+        assert!(matches!(
+            governor.check(&Method::GET, now + Duration::hours(2)),
+            Ok(None)
+        ));
+    }
+}
@@ -2,13 +2,23 @@
 #[deny(clippy::all)]
 #[deny(unsafe_code)]
 pub mod client;
+pub mod endpoint;
 pub mod error;
+pub mod governor;
 pub mod response;
+pub mod retry;
 pub mod traits;
 
 pub mod endpoints {
     //! API endpoints
     pub mod account;
+    pub mod album;
     pub mod authorization;
+    pub mod comment;
+    pub mod conversation;
+    pub mod credits;
+    pub mod custom_gallery;
     pub mod gallery;
+    pub mod image;
+    pub mod notification;
 }
@@ -1,7 +1,11 @@
 //! API response implementation
 use reqwest::header::HeaderMap;
+use std::convert::TryFrom;
+use time::{Duration, OffsetDateTime};
 
-use imgurs_model::model::basic::Basic;
+use imgurs_model::model::basic::{Basic, Data};
+
+use crate::error::{ClientError, ErrorContext};
 
 /// API response container
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -11,3 +15,225 @@ pub struct Response<T> {
     /// HTTP headers in the response
     pub headers: HeaderMap,
 }
+
+impl<T> Response<T> {
+    /// Rate limit information reported in the response headers
+    pub fn rate_limit(&self) -> RateLimit {
+        RateLimit::from_headers(&self.headers)
+    }
+
+    /// Convert the response into its content, or the error matching its status
+    pub fn into_result(self) -> Result<T, ClientError> {
+        let rate_limit = self.rate_limit();
+        match self.content.data {
+            Data::Content(content) => Ok(content),
+            Data::Error {
+                error,
+                request,
+                method,
+            } => Err(ClientError::from_context(ErrorContext {
+                status: self.content.status,
+                message: error,
+                request,
+                method,
+                rate_limit,
+            })),
+        }
+    }
+}
+
+/// Rate limit information of a response
+///
+/// Every value is optional, as the API only reports the ones relevant to the request.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Total credits that can be allocated for the user
+    pub user_limit: Option<u64>,
+    /// Credits available to the user
+    pub user_remaining: Option<u64>,
+    /// Time of the reset of the user credits
+    pub user_reset: Option<OffsetDateTime>,
+    /// Total credits that can be allocated for the application in a day
+    pub client_limit: Option<u64>,
+    /// Credits remaining for the application in the day
+    pub client_remaining: Option<u64>,
+    /// Total POST requests that can be made in an hour
+    pub post_limit: Option<u64>,
+    /// POST requests remaining in the hour
+    pub post_remaining: Option<u64>,
+    /// Time left before the reset of the POST budget
+    pub post_reset: Option<Duration>,
+}
+
+impl RateLimit {
+    /// Parse the `X-RateLimit-*` and `X-Post-Rate-Limit-*` headers, skipping missing or malformed values
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<i64>().ok())
+        };
+        let parse_count = |name: &str| parse(name).and_then(|v| u64::try_from(v).ok());
+
+        RateLimit {
+            user_limit: parse_count("X-RateLimit-UserLimit"),
+            user_remaining: parse_count("X-RateLimit-UserRemaining"),
+            user_reset: parse("X-RateLimit-UserReset")
+                .and_then(|v| OffsetDateTime::from_unix_timestamp(v).ok()),
+            client_limit: parse_count("X-RateLimit-ClientLimit"),
+            client_remaining: parse_count("X-RateLimit-ClientRemaining"),
+            post_limit: parse_count("X-Post-Rate-Limit-Limit"),
+            post_remaining: parse_count("X-Post-Rate-Limit-Remaining"),
+            post_reset: parse("X-Post-Rate-Limit-Reset").map(Duration::seconds),
+        }
+    }
+
+    /// Time of the reset of the exhausted budget, if known
+    pub fn reset_at(&self) -> Option<OffsetDateTime> {
+        if self.post_remaining == Some(0) {
+            return self
+                .post_reset
+                .map(|reset| OffsetDateTime::now_utc() + reset);
+        }
+        if self.user_remaining == Some(0) {
+            return self.user_reset;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ClientError,
+        response::{RateLimit, Response},
+    };
+    use imgurs_model::{
+        error::ErrorMessage,
+        model::basic::{Basic, Data, Method},
+    };
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use time::Duration;
+
+    fn error_response(status: u16, headers: HeaderMap) -> Response<bool> {
+        Response {
+            content: Basic {
+                data: Data::Error {
+                    error: ErrorMessage::new("Error"),
+                    request: "/3/image/abc".to_owned(),
+                    method: Method::GET,
+                },
+                success: false,
+                status,
+            },
+            headers,
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-userlimit", HeaderValue::from_static("2000"));
+        headers.insert(
+            "x-ratelimit-userremaining",
+            HeaderValue::from_static("1999"),
+        );
+        headers.insert(
+            "x-ratelimit-userreset",
+            HeaderValue::from_static("1636137541"),
+        );
+        headers.insert("x-ratelimit-clientlimit", HeaderValue::from_static("12500"));
+        headers.insert(
+            "x-ratelimit-clientremaining",
+            HeaderValue::from_static("oops"),
+        );
+        headers.insert("x-post-rate-limit-limit", HeaderValue::from_static("1250"));
+        headers.insert(
+            "x-post-rate-limit-remaining",
+            HeaderValue::from_static("1249"),
+        );
+        headers.insert("x-post-rate-limit-reset", HeaderValue::from_static("3600"));
+
+        let rate_limit = RateLimit::from_headers(&headers);
+
+        assert_eq!(rate_limit.user_limit, Some(2000));
+        assert_eq!(rate_limit.user_remaining, Some(1999));
+        assert_eq!(
+            rate_limit.user_reset.map(|t| t.unix_timestamp()),
+            Some(1636137541)
+        );
+        assert_eq!(rate_limit.client_limit, Some(12500));
+        assert_eq!(rate_limit.client_remaining, None);
+        assert_eq!(rate_limit.post_limit, Some(1250));
+        assert_eq!(rate_limit.post_remaining, Some(1249));
+        assert_eq!(rate_limit.post_reset, Some(Duration::hours(1)));
+    }
+
+    #[test]
+    fn test_rate_limit_missing_headers() {
+        assert_eq!(
+            RateLimit::from_headers(&HeaderMap::new()),
+            RateLimit::default()
+        );
+    }
+
+    #[test]
+    fn test_into_result() {
+        let res = Response {
+            content: Basic {
+                data: Data::Content(true),
+                success: true,
+                status: 200,
+            },
+            headers: HeaderMap::new(),
+        };
+        assert!(matches!(res.into_result(), Ok(true)));
+
+        match error_response(404, HeaderMap::new()).into_result() {
+            Err(ClientError::NotFound(context)) => {
+                assert_eq!(context.status, 404);
+                assert_eq!(context.request, "/3/image/abc");
+                assert_eq!(context.method, Method::GET);
+            }
+            res => panic!("Unexpected result: {:?}", res),
+        }
+        assert!(matches!(
+            error_response(401, HeaderMap::new()).into_result(),
+            Err(ClientError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            error_response(403, HeaderMap::new()).into_result(),
+            Err(ClientError::Forbidden(_))
+        ));
+        assert!(matches!(
+            error_response(503, HeaderMap::new()).into_result(),
+            Err(ClientError::ServerError(_))
+        ));
+        assert!(matches!(
+            error_response(400, HeaderMap::new()).into_result(),
+            Err(ClientError::ApiError(_))
+        ));
+    }
+
+    #[test]
+    fn test_into_result_rate_limited() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-userremaining", HeaderValue::from_static("0"));
+        headers.insert(
+            "x-ratelimit-userreset",
+            HeaderValue::from_static("1636137541"),
+        );
+
+        match error_response(429, headers).into_result() {
+            Err(ClientError::RateLimited {
+                reset_at,
+                context: Some(context),
+            }) => {
+                assert_eq!(reset_at.map(|t| t.unix_timestamp()), Some(1636137541));
+                assert_eq!(context.rate_limit.user_remaining, Some(0));
+            }
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+}
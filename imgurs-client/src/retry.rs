@@ -0,0 +1,218 @@
+//! Request retry policy implementation
+//!
+//! Imgur often answers with transient failures, such as 429, 500 and "over capacity" 503 responses.
+//! The retry policy decides which failed requests are sent again and how long to wait before.
+use std::{
+    collections::hash_map::RandomState,
+    convert::TryFrom,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use time::OffsetDateTime;
+
+use crate::response::RateLimit;
+
+/// Request retry policy
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts of a request, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled at each further attempt
+    pub base_delay: Duration,
+    /// Longest delay before a retry, requests that should wait longer are not retried
+    pub max_delay: Duration,
+    /// Retry the requests that are not safe to repeat (POST, PUT, DELETE, ...)
+    pub retry_mutating: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_mutating: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy never retrying a request
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Whether requests with the given method can be retried
+    pub fn allows(&self, method: &Method) -> bool {
+        self.retry_mutating || matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+    }
+
+    /// Whether the status is a transient failure
+    pub fn is_transient(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Delay before retrying a request that failed with the given response, if it should be retried
+    ///
+    /// `attempt` is the number of the failed attempt, starting from 1.
+    /// The `Retry-After` and the rate limit reset headers take precedence over the backoff.
+    pub fn response_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if !Self::is_transient(status) {
+            return None;
+        }
+        let delay = match retry_after(headers) {
+            Some(delay) => delay,
+            None if status == StatusCode::TOO_MANY_REQUESTS => {
+                reset_delay(method, &RateLimit::from_headers(headers))
+                    .unwrap_or_else(|| self.backoff(attempt))
+            }
+            None => self.backoff(attempt),
+        };
+        if delay > self.max_delay {
+            return None;
+        }
+        Some(delay)
+    }
+
+    /// Delay before retrying a request that failed without a response, if it should be retried
+    pub fn error_delay(&self, attempt: u32, error: &reqwest::Error) -> Option<Duration> {
+        if error.is_timeout() || error.is_connect() {
+            Some(self.backoff(attempt))
+        } else {
+            None
+        }
+    }
+
+    /// Exponential backoff with jitter, between half and the whole of the doubled delay
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let half = delay / 2;
+        let jitter = RandomState::new().build_hasher().finish() % (half.as_millis() as u64 + 1);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// Delay requested by the `Retry-After` header, in seconds
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get("Retry-After")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Time left before the reset of the exhausted rate limit budget
+fn reset_delay(method: &Method, rate_limit: &RateLimit) -> Option<Duration> {
+    if *method == Method::POST && rate_limit.post_remaining == Some(0) {
+        return rate_limit
+            .post_reset
+            .and_then(|reset| Duration::try_from(reset).ok());
+    }
+    if rate_limit.user_remaining == Some(0) {
+        return rate_limit
+            .user_reset
+            .and_then(|reset| Duration::try_from(reset - OffsetDateTime::now_utc()).ok());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::retry::RetryPolicy;
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        Method, StatusCode,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_retry_allowed_methods() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows(&Method::GET));
+        assert!(!policy.allows(&Method::POST));
+        assert!(!policy.allows(&Method::DELETE));
+
+        let policy = RetryPolicy {
+            retry_mutating: true,
+            ..RetryPolicy::default()
+        };
+        assert!(policy.allows(&Method::POST));
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let policy = RetryPolicy::default();
+        let headers = HeaderMap::new();
+
+        assert_eq!(
+            policy.response_delay(1, &Method::GET, StatusCode::NOT_FOUND, &headers),
+            None
+        );
+        for attempt in 1..=3 {
+            let delay = policy
+                .response_delay(
+                    attempt,
+                    &Method::GET,
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    &headers,
+                )
+                .expect("Transient failures are retried");
+            let full = Duration::from_millis(500 * 2u64.pow(attempt - 1));
+            assert!(delay >= full / 2 && delay <= full);
+        }
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static("7"));
+
+        assert_eq!(
+            policy.response_delay(1, &Method::GET, StatusCode::TOO_MANY_REQUESTS, &headers),
+            Some(Duration::from_secs(7))
+        );
+
+        headers.insert("Retry-After", HeaderValue::from_static("3600"));
+        assert_eq!(
+            policy.response_delay(1, &Method::GET, StatusCode::TOO_MANY_REQUESTS, &headers),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_post_rate_limit_reset() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Post-Rate-Limit-Remaining", HeaderValue::from_static("0"));
+        headers.insert("X-Post-Rate-Limit-Reset", HeaderValue::from_static("12"));
+
+        assert_eq!(
+            policy.response_delay(1, &Method::POST, StatusCode::TOO_MANY_REQUESTS, &headers),
+            Some(Duration::from_secs(12))
+        );
+    }
+}
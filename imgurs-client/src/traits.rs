@@ -1,13 +1,24 @@
 //! API common traits
 use crate::{
     client::{AuthenticationSettings, ClientSettings},
+    endpoint::{parse_response, Base, Body, Endpoint},
     error::ClientError,
+    governor::RateLimitGovernor,
+    response::{RateLimit, Response},
+    retry::RetryPolicy,
 };
+use async_trait::async_trait;
 use imgurs_model::model::authorization::AccessToken;
-use reqwest::{header::HeaderMap, Client as ReqwestClient};
+use reqwest::{
+    header::HeaderMap, Client as ReqwestClient, RequestBuilder, Response as ReqwestResponse,
+};
+use serde::de::DeserializeOwned;
 use time::OffsetDateTime;
+use tracing::{debug, warn};
+use url::Url;
 
 /// Generic client trait
+#[async_trait]
 pub trait Client: Clone + Sync {
     /// Get default request headers
     fn get_headers(&self) -> Result<HeaderMap, ClientError>;
@@ -17,6 +28,125 @@ pub trait Client: Clone + Sync {
 
     /// Get client settings
     fn get_settings(&self) -> &ClientSettings;
+
+    /// Build the URL of an API endpoint from its path, relative to the API base URL
+    fn api_url(&self, path: &str) -> Result<Url, ClientError> {
+        join_url(&self.get_settings().api_url, path)
+    }
+
+    /// Build the URL of an OAuth endpoint from its path, relative to the OAuth base URL
+    fn oauth_url(&self, path: &str) -> Result<Url, ClientError> {
+        join_url(&self.get_settings().oauth_url, path)
+    }
+
+    /// Build the full URL of an endpoint, with its query parameters
+    fn endpoint_url<T>(&self, endpoint: &Endpoint<T>) -> Result<Url, ClientError> {
+        let mut url = match endpoint.base() {
+            Base::Api => self.api_url(endpoint.path())?,
+            Base::OAuth => self.oauth_url(endpoint.path())?,
+        };
+        if !endpoint.query_parameters().is_empty() {
+            url.query_pairs_mut()
+                .extend_pairs(endpoint.query_parameters());
+        }
+        Ok(url)
+    }
+
+    /// Get the rate limit governor, if enabled
+    fn get_governor(&self) -> Option<&RateLimitGovernor> {
+        None
+    }
+
+    /// Get the retry policy, if enabled
+    fn get_retry_policy(&self) -> Option<&RetryPolicy> {
+        None
+    }
+
+    /// Whether the client acts on behalf of an authenticated user
+    fn is_authenticated(&self) -> bool {
+        false
+    }
+
+    /// Send the request of an endpoint and parse its response
+    async fn execute<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint<T>,
+    ) -> Result<Response<T>, ClientError> {
+        if endpoint.requires_auth() && !self.is_authenticated() {
+            return Err(ClientError::AuthenticationRequired);
+        }
+        let url = self.endpoint_url(&endpoint)?;
+
+        debug!("Request: {} {}", endpoint.method(), endpoint.path());
+
+        let mut request = self
+            .get_client()
+            .request(endpoint.method().clone(), url)
+            .headers(self.get_headers()?);
+        let method = endpoint.method().clone();
+        let base = endpoint.base();
+        let (body, envelope) = endpoint.into_parts();
+        request = match body {
+            Body::Empty => request,
+            Body::Form(params) => request.form(&params),
+            Body::Multipart(form) => request.multipart(form),
+        };
+
+        parse_response(self.send(request).await?, &method, base, envelope).await
+    }
+
+    /// Send a request, through the rate limit governor and the retry policy if enabled
+    async fn send(&self, request: RequestBuilder) -> Result<ReqwestResponse, ClientError> {
+        let mut request = request.build()?;
+        let mut attempt = 1;
+        loop {
+            let retry_policy = self
+                .get_retry_policy()
+                .filter(|policy| attempt < policy.max_attempts && policy.allows(request.method()));
+            let retry_request = retry_policy.and_then(|_| request.try_clone());
+            let method = request.method().clone();
+            let url = request.url().clone();
+
+            if let Some(governor) = self.get_governor() {
+                governor.acquire(&method).await?;
+            }
+            let res = self.get_client().execute(request).await;
+            if let (Some(governor), Ok(res)) = (self.get_governor(), &res) {
+                governor.record(&RateLimit::from_headers(res.headers()));
+            }
+
+            let retry = retry_policy
+                .zip(retry_request)
+                .and_then(|(policy, retry_request)| {
+                    let delay = match &res {
+                        Ok(res) => {
+                            policy.response_delay(attempt, &method, res.status(), res.headers())
+                        }
+                        Err(e) => policy.error_delay(attempt, e),
+                    };
+                    delay.map(|delay| (retry_request, delay))
+                });
+            match retry {
+                Some((retry_request, delay)) => {
+                    warn!(
+                        "Retrying {} {} in {:?} (attempt {} failed: {})",
+                        method,
+                        url.path(),
+                        delay,
+                        attempt,
+                        match &res {
+                            Ok(res) => res.status().to_string(),
+                            Err(e) => e.to_string(),
+                        }
+                    );
+                    tokio::time::sleep(delay).await;
+                    request = retry_request;
+                    attempt += 1;
+                }
+                None => return res.map_err(Into::into),
+            }
+        }
+    }
 }
 
 /// Registered client trait
@@ -30,3 +160,43 @@ pub trait RegisteredClient: Client {
         expires_in: OffsetDateTime,
     );
 }
+
+/// Append a path to a base URL, regardless of its trailing slash
+fn join_url(base: &Url, path: &str) -> Result<Url, ClientError> {
+    Url::parse(&format!("{}/{}", base.as_str().trim_end_matches('/'), path)).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::BasicClient, traits::Client};
+    use imgurs_model::model::authorization::{ClientID, ClientSecret};
+    use std::error::Error;
+    use url::Url;
+
+    #[test]
+    fn test_api_url() -> Result<(), Box<dyn Error>> {
+        let client = BasicClient::new(ClientID("id".into()), ClientSecret("secret".into()))?;
+        assert_eq!(
+            client.api_url("3/image/abc")?.as_str(),
+            "https://api.imgur.com/3/image/abc"
+        );
+        assert_eq!(
+            client.oauth_url("token")?.as_str(),
+            "https://api.imgur.com/oauth2/token"
+        );
+
+        let client = client
+            .with_api_url(Url::parse("http://localhost:8080/mock")?)
+            .with_oauth_url(Url::parse("http://localhost:8080/mock/oauth2/")?);
+        assert_eq!(
+            client.api_url("3/image/abc?x=1")?.as_str(),
+            "http://localhost:8080/mock/3/image/abc?x=1"
+        );
+        assert_eq!(
+            client.oauth_url("token")?.as_str(),
+            "http://localhost:8080/mock/oauth2/token"
+        );
+
+        Ok(())
+    }
+}
@@ -87,11 +87,62 @@ pub struct BlockResponse {
     pub blocked: bool,
 }
 
+/// Avatar and cover of an account
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// #[serde(deny_unknown_fields)]
+pub struct AccountAvatar {
+    /// Avatar URL
+    pub avatar: Option<Url>,
+    /// Avatar name
+    pub avatar_name: Option<String>,
+    /// Cover image
+    pub cover: Option<Url>,
+    /// Cover name
+    pub cover_name: Option<String>,
+}
+
+/// Avatar or cover image the account can choose
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// #[serde(deny_unknown_fields)]
+pub struct AvatarChoice {
+    /// Name of the image, used to select it
+    pub name: String,
+    /// URL of the image
+    pub location: Url,
+}
+
+/// Avatars available to the account
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// #[serde(deny_unknown_fields)]
+pub struct AvailableAvatars {
+    /// Available avatars
+    pub available_avatars: Vec<AvatarChoice>,
+    /// TODO: missing from API model
+    pub count: Option<u64>,
+}
+
+/// Covers available to the account
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// #[serde(deny_unknown_fields)]
+pub struct AvailableCovers {
+    /// Available covers
+    pub available_covers: Vec<AvatarChoice>,
+    /// TODO: missing from API model
+    pub count: Option<u64>,
+}
+
 #[cfg(test)]
 mod test {
     use std::error::Error;
 
-    use crate::model::{account::Account, basic::Basic};
+    use crate::model::{
+        account::{Account, AvailableAvatars},
+        basic::Basic,
+    };
 
     #[test]
     fn test_deserialize_account_local() -> Result<(), Box<dyn Error>> {
@@ -103,4 +154,15 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_deserialize_available_avatars_local() -> Result<(), Box<dyn Error>> {
+        let data = r#"{"data":{"available_avatars":[{"name":"default/G","location":"https://i.imgur.com/avatar/default/G.png"}],"count":1},"success":true,"status":200}"#;
+
+        let avatars = serde_json::from_str::<Basic<AvailableAvatars>>(data)?.result()?;
+
+        assert_eq!(avatars.available_avatars[0].name, "default/G");
+
+        Ok(())
+    }
 }
@@ -2,7 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::model::common::{AccountID, ProExpiration};
+use crate::model::{
+    album::AlbumPrivacy,
+    common::{AccountID, ProExpiration},
+};
 
 /// The account settings, only accessible if you're logged in as the user.
 ///
@@ -18,7 +21,7 @@ pub struct AccountSettings {
     /// Automatically allow all images to be publicly accessible
     public_images: bool,
     /// Set the album privacy to this privacy setting on creation
-    album_privacy: String,
+    album_privacy: AlbumPrivacy,
     /// False if not a pro user, their expiration date if they are.
     pro_expiration: ProExpiration,
     /// True if the user has accepted the terms of uploading to the Imgur gallery.
@@ -35,6 +38,63 @@ pub struct AccountSettings {
     first_party: bool,
 }
 
+impl AccountSettings {
+    /// The account username
+    pub fn account_url(&self) -> Option<&str> {
+        self.account_url.as_deref()
+    }
+
+    /// The users email address
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    /// Automatically allow all images to be publicly accessible
+    pub fn public_images(&self) -> bool {
+        self.public_images
+    }
+
+    /// Set the album privacy to this privacy setting on creation
+    pub fn album_privacy(&self) -> AlbumPrivacy {
+        self.album_privacy
+    }
+
+    /// False if not a pro user, their expiration date if they are.
+    pub fn pro_expiration(&self) -> &ProExpiration {
+        &self.pro_expiration
+    }
+
+    /// True if the user has accepted the terms of uploading to the Imgur gallery.
+    pub fn accepted_gallery_terms(&self) -> bool {
+        self.accepted_gallery_terms
+    }
+
+    /// The email addresses that have been activated to allow uploading
+    pub fn active_emails(&self) -> &[String] {
+        &self.active_emails
+    }
+
+    /// If the user is accepting incoming messages or not
+    pub fn messaging_enabled(&self) -> bool {
+        self.messaging_enabled
+    }
+
+    /// The users that have been blocked from messaging
+    pub fn blocked_users(&self) -> &[BlockedUser] {
+        &self.blocked_users
+    }
+
+    /// True if the user has opted to have mature images displayed in gallery list endpoints.
+    pub fn show_mature(&self) -> bool {
+        self.show_mature
+    }
+
+    /// True unless the user created their account via a third party service such as Google Plus.
+    pub fn first_party(&self) -> bool {
+        self.first_party
+    }
+}
+
 /// Blocked user entry
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -51,6 +111,7 @@ mod test {
     use std::error::Error;
 
     use crate::model::account_settings::AccountSettings;
+    use crate::model::album::AlbumPrivacy;
     use crate::model::basic::Basic;
 
     #[test]
@@ -75,9 +136,11 @@ mod test {
             "status": 200
         }"#;
 
-        let account_settings = serde_json::from_str::<Basic<AccountSettings>>(data)?;
+        let account_settings = serde_json::from_str::<Basic<AccountSettings>>(data)?.result()?;
 
         println!("{:#?}", account_settings);
+        assert_eq!(account_settings.album_privacy(), AlbumPrivacy::Secret);
+        assert_eq!(account_settings.blocked_users()[0].blocked_url, "joshTest");
 
         Ok(())
     }
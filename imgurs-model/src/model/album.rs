@@ -2,7 +2,7 @@
 
 use std::fmt;
 
-use crate::model::common::AccountID;
+use crate::model::{common::AccountID, image::Image};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use time::{serde::timestamp, OffsetDateTime};
@@ -27,6 +27,61 @@ impl fmt::Display for AlbumID {
     }
 }
 
+/// Album privacy level
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlbumPrivacy {
+    /// Visible to everyone
+    Public,
+    /// Only visible to users with the link
+    Hidden,
+    /// Only visible to the owner
+    Secret,
+}
+
+impl fmt::Display for AlbumPrivacy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                AlbumPrivacy::Public => "public",
+                AlbumPrivacy::Hidden => "hidden",
+                AlbumPrivacy::Secret => "secret",
+            }
+        )
+    }
+}
+
+/// Album view layout
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlbumLayout {
+    /// Images one after the other, with their descriptions
+    Blog,
+    /// Grid of thumbnails
+    Grid,
+    /// Horizontal strip of images
+    Horizontal,
+    /// Vertical strip of images
+    Vertical,
+}
+
+impl fmt::Display for AlbumLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                AlbumLayout::Blog => "blog",
+                AlbumLayout::Grid => "grid",
+                AlbumLayout::Horizontal => "horizontal",
+                AlbumLayout::Vertical => "vertical",
+            }
+        )
+    }
+}
+
 /// The base model for an album
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -54,11 +109,9 @@ pub struct Album {
     /// The account ID or null if it's anonymous.
     pub account_id: Option<AccountID>,
     /// The privacy level of the album, you can only view public if not logged in as album owner
-    // TODO: Switch to enum
-    pub privacy: String,
+    pub privacy: AlbumPrivacy,
     /// The view layout of the album.
-    // TODO: Switch to enum
-    pub layout: String,
+    pub layout: AlbumLayout,
     /// The number of album views
     pub views: u64,
     /// The URL link to the album
@@ -77,9 +130,8 @@ pub struct Album {
     /// The total number of images in the album
     pub images_count: u64,
     /// An array of all the images in the album (only available when requesting the direct album)
-    ///
-    /// TODO: switch to image object
-    pub images: Vec<Value>,
+    #[serde(default)]
+    pub images: Vec<Image>,
     /// True if the image has been submitted to the gallery, false if otherwise.
     pub in_gallery: bool,
     /// True if the image is an ad
@@ -92,11 +144,28 @@ pub struct Album {
     pub ad_config: Value,
 }
 
+/// Album creation response
+///
+/// Anonymous albums can only be edited through their deletehash.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct CreatedAlbum {
+    /// The ID for the album
+    pub id: AlbumID,
+    /// The deletehash of the album
+    #[serde(rename = "deletehash")]
+    pub delete_hash: Option<String>,
+}
+
 #[cfg(test)]
 mod test {
     use std::error::Error;
 
-    use crate::model::{album::Album, basic::Basic};
+    use crate::model::{
+        album::{Album, CreatedAlbum},
+        basic::Basic,
+    };
 
     #[test]
     fn test_deserialize_album_local() -> Result<(), Box<dyn Error>> {
@@ -108,4 +177,16 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_deserialize_created_album_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{"data":{"id":"ZQkLV5p","deletehash":"SYXBUifvLfA2KWR"},"success":true,"status":200}"#;
+
+        let data = serde_json::from_str::<Basic<CreatedAlbum>>(res)?.result()?;
+
+        assert_eq!(data.id, "ZQkLV5p".into());
+        assert_eq!(data.delete_hash.as_deref(), Some("SYXBUifvLfA2KWR"));
+
+        Ok(())
+    }
 }
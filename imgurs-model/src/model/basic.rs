@@ -29,6 +29,18 @@ impl<T> Basic<T> {
     pub fn result(self) -> Result<T, ErrorMessage> {
         self.into()
     }
+
+    /// Map the response content, keeping the response status and errors
+    pub fn map<U, F>(self, f: F) -> Basic<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Basic {
+            data: self.data.map(f),
+            success: self.success,
+            status: self.status,
+        }
+    }
 }
 
 /// API response data
@@ -50,6 +62,27 @@ pub enum Data<T> {
     },
 }
 
+impl<T> Data<T> {
+    /// Map the content, keeping errors
+    pub fn map<U, F>(self, f: F) -> Data<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Data::Content(c) => Data::Content(f(c)),
+            Data::Error {
+                error,
+                request,
+                method,
+            } => Data::Error {
+                error,
+                request,
+                method,
+            },
+        }
+    }
+}
+
 impl<T> From<Data<T>> for Result<T, ErrorMessage> {
     fn from(d: Data<T>) -> Self {
         match d {
@@ -1,16 +1,21 @@
 //! Comment specification
 
-use crate::model::common::{AccountID, Username};
+use std::collections::VecDeque;
+
+use crate::model::common::{AccountID, Username, Vote};
 use serde::{Deserialize, Serialize};
 use time::{serde::timestamp, OffsetDateTime};
 
+/// Unique identifier of a comment
+pub type CommentID = u64;
+
 /// The base model for a comment.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 // #[serde(deny_unknown_fields)]
 pub struct Comment {
     /// The ID for the comment
-    pub id: u64,
+    pub id: CommentID,
     /// The ID of the image that the comment is for
     pub image_id: String,
     /// The comment itself.
@@ -35,11 +40,11 @@ pub struct Comment {
     /// If this is a reply, this will be the value of the comment_id for the caption this a reply for.
     ///
     /// Defaults to 0 if it isn't a reply
-    pub parent_id: u64,
+    pub parent_id: CommentID,
     /// Marked true if this caption has been deleted
     pub deleted: bool,
     /// The current user's vote on the comment. null if not signed in or if the user hasn't voted on it.
-    pub vote: Option<String>,
+    pub vote: Option<Vote>,
     /// TODO: missing from API model
     pub platform: String,
     /// TODO: missing from API model
@@ -48,11 +53,153 @@ pub struct Comment {
     pub children: Vec<Comment>,
 }
 
+impl Comment {
+    /// Depth-first (pre-order) iterator over the comment and all of its replies
+    pub fn depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst::new(std::slice::from_ref(self))
+    }
+
+    /// Breadth-first iterator over the comment and all of its replies
+    pub fn breadth_first(&self) -> BreadthFirst<'_> {
+        BreadthFirst::new(std::slice::from_ref(self))
+    }
+
+    /// Find a comment by id between the comment and all of its replies
+    pub fn find(&self, id: CommentID) -> Option<&Comment> {
+        self.depth_first().find(|c| c.id == id)
+    }
+
+    /// Flatten the comment and all of its replies in depth-first order
+    pub fn flatten(&self) -> Vec<ThreadEntry<'_>> {
+        flatten_thread(std::slice::from_ref(self))
+    }
+}
+
+/// Depth-first (pre-order) iterator over a comment thread
+#[derive(Clone, Debug)]
+pub struct DepthFirst<'a> {
+    stack: Vec<&'a Comment>,
+}
+
+impl<'a> DepthFirst<'a> {
+    /// Iterate over the given top level comments and all of their replies
+    pub fn new(roots: &'a [Comment]) -> Self {
+        DepthFirst {
+            stack: roots.iter().rev().collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = &'a Comment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let comment = self.stack.pop()?;
+        self.stack.extend(comment.children.iter().rev());
+        Some(comment)
+    }
+}
+
+/// Breadth-first iterator over a comment thread
+#[derive(Clone, Debug)]
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<&'a Comment>,
+}
+
+impl<'a> BreadthFirst<'a> {
+    /// Iterate over the given top level comments and all of their replies
+    pub fn new(roots: &'a [Comment]) -> Self {
+        BreadthFirst {
+            queue: roots.iter().collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = &'a Comment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let comment = self.queue.pop_front()?;
+        self.queue.extend(comment.children.iter());
+        Some(comment)
+    }
+}
+
+/// Entry of a flattened comment thread
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThreadEntry<'a> {
+    /// The comment
+    pub comment: &'a Comment,
+    /// Nesting level, 0 for top level comments
+    pub depth: usize,
+    /// The comment this is a reply to, `None` for top level comments
+    pub parent: Option<&'a Comment>,
+}
+
+/// Flatten the given top level comments and all of their replies in depth-first order
+pub fn flatten_thread(roots: &[Comment]) -> Vec<ThreadEntry<'_>> {
+    let mut entries = Vec::new();
+    let mut stack: Vec<ThreadEntry<'_>> = roots
+        .iter()
+        .rev()
+        .map(|comment| ThreadEntry {
+            comment,
+            depth: 0,
+            parent: None,
+        })
+        .collect();
+
+    while let Some(entry) = stack.pop() {
+        stack.extend(
+            entry
+                .comment
+                .children
+                .iter()
+                .rev()
+                .map(|child| ThreadEntry {
+                    comment: child,
+                    depth: entry.depth + 1,
+                    parent: Some(entry.comment),
+                }),
+        );
+        entries.push(entry);
+    }
+
+    entries
+}
+
+/// Comment creation response
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct CreatedComment {
+    /// The ID of the new comment
+    pub id: CommentID,
+}
+
 #[cfg(test)]
 mod test {
     use std::error::Error;
 
-    use crate::model::{basic::Basic, comment::Comment};
+    use crate::model::{
+        basic::Basic,
+        comment::{flatten_thread, BreadthFirst, Comment},
+    };
+
+    fn comment_tree() -> Result<Comment, serde_json::Error> {
+        let reply = |id: u64, parent_id: u64, children: &str| {
+            format!(
+                r#"{{"id":{},"image_id":"CRprgNU","comment":"Reply {}","author":"imgurs","author_id":1,"on_album":true,"album_cover":"w2gwdJp","ups":1,"downs":0,"points":1,"datetime":1599059372,"parent_id":{},"deleted":false,"vote":null,"platform":"desktop","has_admin_badge":false,"children":[{}]}}"#,
+                id, id, parent_id, children
+            )
+        };
+        let tree = reply(
+            1,
+            0,
+            &[reply(2, 1, &reply(4, 2, "")), reply(3, 1, "")].join(","),
+        );
+        serde_json::from_str(&tree)
+    }
 
     #[test]
     fn test_deserialize_comment_local() -> Result<(), Box<dyn Error>> {
@@ -64,4 +211,47 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_comment_tree_traversal() -> Result<(), Box<dyn Error>> {
+        let root = comment_tree()?;
+
+        let depth_first: Vec<u64> = root.depth_first().map(|c| c.id).collect();
+        assert_eq!(depth_first, vec![1, 2, 4, 3]);
+
+        let breadth_first: Vec<u64> = root.breadth_first().map(|c| c.id).collect();
+        assert_eq!(breadth_first, vec![1, 2, 3, 4]);
+
+        let roots = vec![root.clone(), root.children[1].clone()];
+        let breadth_first: Vec<u64> = BreadthFirst::new(&roots).map(|c| c.id).collect();
+        assert_eq!(breadth_first, vec![1, 3, 2, 3, 4]);
+
+        assert_eq!(root.find(4).map(|c| c.parent_id), Some(2));
+        assert!(root.find(5).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_comment_tree_flatten() -> Result<(), Box<dyn Error>> {
+        let root = comment_tree()?;
+
+        let entries: Vec<(u64, usize, Option<u64>)> = root
+            .flatten()
+            .iter()
+            .map(|e| (e.comment.id, e.depth, e.parent.map(|p| p.id)))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (1, 0, None),
+                (2, 1, Some(1)),
+                (4, 2, Some(2)),
+                (3, 1, Some(1))
+            ]
+        );
+        assert_eq!(flatten_thread(&root.children).len(), 3);
+
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
 //! Common data objects
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 use time::{serde::timestamp, OffsetDateTime};
 
@@ -22,3 +24,85 @@ pub enum ProExpiration {
     /// Boolean of whether the account has a pro status
     Bool(bool),
 }
+
+/// Favorite toggle outcome
+///
+/// Favorite endpoints toggle the status and return the new one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FavoriteStatus {
+    /// The item is now in the user favorites
+    Favorited,
+    /// The item has been removed from the user favorites
+    Unfavorited,
+}
+
+/// Vote on gallery items and comments
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Vote {
+    /// Upvote
+    Up,
+    /// Downvote
+    Down,
+    /// Remove a previous vote
+    Veto,
+}
+
+impl fmt::Display for Vote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Vote::Up => "up",
+                Vote::Down => "down",
+                Vote::Veto => "veto",
+            }
+        )
+    }
+}
+
+/// Vote on gallery item tags, which cannot be vetoed
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagVote {
+    /// Upvote
+    Up,
+    /// Downvote
+    Down,
+}
+
+impl fmt::Display for TagVote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TagVote::Up => "up",
+                TagVote::Down => "down",
+            }
+        )
+    }
+}
+
+/// Reason of a content report
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReportReason {
+    /// Doesn't belong on Imgur
+    DoesNotBelong = 1,
+    /// Spam
+    Spam = 2,
+    /// Abusive
+    Abusive = 3,
+    /// Mature content not marked as mature
+    UnmarkedMature = 4,
+    /// Pornography
+    Pornography = 5,
+}
+
+impl fmt::Display for ReportReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
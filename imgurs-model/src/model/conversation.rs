@@ -6,6 +6,9 @@ use time::{serde::timestamp, OffsetDateTime};
 use crate::model::common::AccountID;
 use crate::model::message::Message;
 
+/// Unique identifier of a conversation
+pub type ConversationID = u64;
+
 /// The base model for a conversation.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -18,7 +21,7 @@ pub struct Conversation(pub Vec<ConversationEntry>);
 #[serde(deny_unknown_fields)]
 pub struct ConversationEntry {
     /// Conversation ID
-    pub id: u64,
+    pub id: ConversationID,
     /// Preview of the last message
     ///
     /// TODO: check if Option
@@ -0,0 +1,42 @@
+//! Credits specification
+
+use serde::{Deserialize, Serialize};
+use time::{serde::timestamp, OffsetDateTime};
+
+/// Rate limit credits of the client and of the current user
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[serde(deny_unknown_fields)]
+pub struct Credits {
+    /// Total credits that can be allocated
+    pub user_limit: u64,
+    /// Total credits available
+    pub user_remaining: u64,
+    /// Timestamp for when the credits will be reset
+    #[serde(with = "timestamp")]
+    pub user_reset: OffsetDateTime,
+    /// Total credits that can be allocated for the application in a day
+    pub client_limit: u64,
+    /// Total credits remaining for the application in a day
+    pub client_remaining: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use crate::model::{basic::Basic, credits::Credits};
+
+    #[test]
+    fn test_deserialize_credits_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{"data":{"UserLimit":500,"UserRemaining":499,"UserReset":1636137541,"ClientLimit":12500,"ClientRemaining":12498},"success":true,"status":200}"#;
+
+        let data = serde_json::from_str::<Basic<Credits>>(res)?.result()?;
+
+        assert_eq!(data.user_remaining, 499);
+        assert_eq!(data.user_reset.unix_timestamp(), 1636137541);
+        assert_eq!(data.client_limit, 12500);
+
+        Ok(())
+    }
+}
@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::model::{common::Username, gallery_album::GalleryAlbum, gallery_image::GalleryImage};
+use crate::model::{
+    album::Album, common::Username, gallery_album::GalleryAlbum, gallery_image::GalleryImage,
+    image::Image,
+};
 
 /// Custom gallery
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -32,12 +35,30 @@ pub enum CustomGalleryItem {
     GalleryAlbum(GalleryAlbum),
 }
 
+/// Favorited item of an account
+///
+/// Favorites are not necessarily shared in the gallery.
+/// The strict image and album models are tried before the lenient gallery ones.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(untagged)]
+pub enum FavoriteItem {
+    /// Image not shared in the gallery
+    Image(Image),
+    /// Album not shared in the gallery
+    Album(Album),
+    /// Gallery image
+    GalleryImage(GalleryImage),
+    /// Gallery album
+    GalleryAlbum(GalleryAlbum),
+}
+
 #[cfg(test)]
 mod test {
     use std::error::Error;
 
     use crate::model::basic::Basic;
-    use crate::model::custom_gallery::CustomGallery;
+    use crate::model::custom_gallery::{CustomGallery, FavoriteItem};
 
     // TODO: use a better example
     #[test]
@@ -60,4 +81,44 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_deserialize_favorite_image_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{
+            "data": [{
+                "id": "orunSTu",
+                "title": null,
+                "description": null,
+                "datetime": 1495556889,
+                "type": "image/gif",
+                "animated": false,
+                "width": 1,
+                "height": 1,
+                "size": 42,
+                "views": 0,
+                "bandwidth": 0,
+                "vote": null,
+                "favorite": true,
+                "nsfw": null,
+                "section": null,
+                "account_url": null,
+                "account_id": 0,
+                "is_ad": false,
+                "in_most_viral": false,
+                "tags": [],
+                "ad_type": 0,
+                "ad_url": "",
+                "in_gallery": false,
+                "link": "http://i.imgur.com/orunSTu.gif"
+            }],
+            "success": true,
+            "status": 200
+        }"#;
+
+        let data = serde_json::from_str::<Basic<Vec<FavoriteItem>>>(res)?.result()?;
+
+        assert!(matches!(data[0], FavoriteItem::Image(_)));
+
+        Ok(())
+    }
 }
@@ -3,7 +3,11 @@ use serde::{Deserialize, Serialize};
 use time::{serde::timestamp, OffsetDateTime};
 use url::Url;
 
-use crate::model::{common::AccountID, gallery_image::GalleryImage};
+use crate::model::{
+    common::{AccountID, Vote},
+    gallery_image::GalleryImage,
+    gallery_tags::Tag,
+};
 
 /// Gallery album
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -69,8 +73,8 @@ pub struct GalleryAlbum {
     pub score: i64,
     /// TODO: missing from API model
     pub section: Option<serde_json::Value>,
-    /// TODO: missing from API model
-    pub tags: Option<Vec<String>>,
+    /// Tags of the gallery album
+    pub tags: Option<Vec<Tag>>,
     /// The title of the album in the gallery
     pub title: String,
     /// Topic of the gallery album.
@@ -82,7 +86,7 @@ pub struct GalleryAlbum {
     /// The number of image views
     pub views: u64,
     /// The current user's vote on the album. `null` if not signed in or if the user hasn't voted on it.
-    pub vote: Option<String>,
+    pub vote: Option<Vote>,
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 //! Gallery image specification
 
-use crate::model::common::AccountID;
+use crate::model::common::{AccountID, Vote};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use time::{serde::timestamp, OffsetDateTime};
@@ -108,7 +108,7 @@ pub struct GalleryImage {
     /// The number of image views
     pub views: u64,
     /// The current user's vote on the album. null if not signed in or if the user hasn't voted on it.
-    pub vote: Option<String>,
+    pub vote: Option<Vote>,
     /// The width of the image in pixels
     pub width: u64,
 }
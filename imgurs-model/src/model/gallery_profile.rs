@@ -10,15 +10,15 @@ use url::Url;
 #[serde(deny_unknown_fields)]
 pub struct GalleryProfile {
     /// Total number of comments the user has made in the gallery
-    total_gallery_comments: u64,
+    pub total_gallery_comments: u64,
     /// Total number of items favorited by the user in the gallery
-    total_gallery_favorites: Option<u64>,
+    pub total_gallery_favorites: Option<u64>,
     /// TODO: missing from API model
-    total_gallery_likes: Option<u64>,
+    pub total_gallery_likes: Option<u64>,
     /// Total number of images submitted by the user.
-    total_gallery_submissions: u64,
+    pub total_gallery_submissions: u64,
     /// An array of trophies that the user has.
-    trophies: Vec<Trophy>,
+    pub trophies: Vec<Trophy>,
 }
 
 /// Gallery trophy
@@ -27,22 +27,22 @@ pub struct GalleryProfile {
 #[serde(deny_unknown_fields)]
 pub struct Trophy {
     /// The ID of the trophy, this is unique to each trophy
-    id: u64,
+    pub id: u64,
     /// The name of the trophy
-    name: String,
+    pub name: String,
     /// Can be thought of as the ID of a trophy type
-    name_clean: String,
+    pub name_clean: String,
     /// A description of the trophy and how it was earned.
-    description: String,
+    pub description: String,
     /// The ID of the image or the ID of the comment where the trophy was earned
-    data: Option<String>,
+    pub data: Option<String>,
     /// A link to where the trophy was earned
-    data_link: Option<Url>,
+    pub data_link: Option<Url>,
     /// Date the trophy was earned, epoch time
     #[serde(with = "timestamp")]
-    datetime: OffsetDateTime,
+    pub datetime: OffsetDateTime,
     /// URL of the image representing the trophy
-    image: Url,
+    pub image: Url,
 }
 
 #[cfg(test)]
@@ -78,9 +78,10 @@ mod test {
         }"#
         };
 
-        let data = serde_json::from_str::<Basic<GalleryProfile>>(res)?;
+        let data = serde_json::from_str::<Basic<GalleryProfile>>(res)?.result()?;
 
         println!("{:#?}", data);
+        assert_eq!(data.trophies[0].name_clean, "1Years");
 
         Ok(())
     }
@@ -0,0 +1,218 @@
+//! Gallery search query specification
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// File type filter of an advanced search
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchFileType {
+    /// JPEG images
+    Jpg,
+    /// PNG images
+    Png,
+    /// Static GIF images
+    Gif,
+    /// Animated GIF images
+    Anigif,
+    /// Albums
+    Album,
+}
+
+impl fmt::Display for SearchFileType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SearchFileType::Jpg => "jpg",
+                SearchFileType::Png => "png",
+                SearchFileType::Gif => "gif",
+                SearchFileType::Anigif => "anigif",
+                SearchFileType::Album => "album",
+            }
+        )
+    }
+}
+
+/// Image size filter of an advanced search
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchImageSize {
+    /// Up to 500 pixels square
+    Small,
+    /// 500 to 2000 pixels square
+    Med,
+    /// 2000 to 5000 pixels square
+    Big,
+    /// 5000 to 10000 pixels square
+    Lrg,
+    /// 10000 pixels square and above
+    Huge,
+}
+
+impl fmt::Display for SearchImageSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SearchImageSize::Small => "small",
+                SearchImageSize::Med => "med",
+                SearchImageSize::Big => "big",
+                SearchImageSize::Lrg => "lrg",
+                SearchImageSize::Huge => "huge",
+            }
+        )
+    }
+}
+
+/// Gallery search query
+///
+/// Either a simple query string or a combination of advanced parameters.
+/// If any advanced parameter is set the simple query string is ignored by the API.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GallerySearchQuery {
+    /// Simple query string, supports the `AND`, `OR` and `NOT` operators and field searches
+    q: Option<String>,
+    /// Search for all of these words
+    q_all: Option<String>,
+    /// Search for any of these words
+    q_any: Option<String>,
+    /// Search for exactly this word or phrase
+    q_exactly: Option<String>,
+    /// Exclude results matching this
+    q_not: Option<String>,
+    /// Show results for this file type only
+    q_type: Option<SearchFileType>,
+    /// Show results for this image size only
+    q_size_px: Option<SearchImageSize>,
+}
+
+impl GallerySearchQuery {
+    /// Empty query constructor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Simple query string
+    pub fn query<T: Into<String>>(mut self, q: T) -> Self {
+        self.q = Some(q.into());
+        self
+    }
+
+    /// Search for all of these words
+    pub fn all<T: Into<String>>(mut self, words: T) -> Self {
+        self.q_all = Some(words.into());
+        self
+    }
+
+    /// Search for any of these words
+    pub fn any<T: Into<String>>(mut self, words: T) -> Self {
+        self.q_any = Some(words.into());
+        self
+    }
+
+    /// Search for exactly this word or phrase
+    pub fn exactly<T: Into<String>>(mut self, phrase: T) -> Self {
+        self.q_exactly = Some(phrase.into());
+        self
+    }
+
+    /// Exclude results matching this
+    pub fn not<T: Into<String>>(mut self, words: T) -> Self {
+        self.q_not = Some(words.into());
+        self
+    }
+
+    /// Show results for this file type only
+    pub fn file_type(mut self, file_type: SearchFileType) -> Self {
+        self.q_type = Some(file_type);
+        self
+    }
+
+    /// Show results for this image size only
+    pub fn size(mut self, size: SearchImageSize) -> Self {
+        self.q_size_px = Some(size);
+        self
+    }
+
+    /// Whether any of the advanced parameters is set
+    pub fn is_advanced(&self) -> bool {
+        self.q_all.is_some()
+            || self.q_any.is_some()
+            || self.q_exactly.is_some()
+            || self.q_not.is_some()
+            || self.q_type.is_some()
+            || self.q_size_px.is_some()
+    }
+
+    /// Encode the query to the respective query parameters, skipping unset values
+    ///
+    /// The simple query string is dropped if any advanced parameter is set.
+    pub fn to_query_parameters(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if !self.is_advanced() {
+            if let Some(q) = &self.q {
+                params.push(("q", q.clone()));
+            }
+            return params;
+        }
+        if let Some(q_all) = &self.q_all {
+            params.push(("q_all", q_all.clone()));
+        }
+        if let Some(q_any) = &self.q_any {
+            params.push(("q_any", q_any.clone()));
+        }
+        if let Some(q_exactly) = &self.q_exactly {
+            params.push(("q_exactly", q_exactly.clone()));
+        }
+        if let Some(q_not) = &self.q_not {
+            params.push(("q_not", q_not.clone()));
+        }
+        if let Some(q_type) = self.q_type {
+            params.push(("q_type", q_type.to_string()));
+        }
+        if let Some(q_size_px) = self.q_size_px {
+            params.push(("q_size_px", q_size_px.to_string()));
+        }
+        params
+    }
+
+    /// Encode the query to a URL encoded query string
+    pub fn to_query_string(&self) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.to_query_parameters())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::gallery_search::{GallerySearchQuery, SearchFileType, SearchImageSize};
+
+    #[test]
+    fn test_simple_query_encoding() {
+        let query = GallerySearchQuery::new().query("cats OR dogs");
+
+        assert_eq!(query.to_query_string(), "q=cats+OR+dogs");
+    }
+
+    #[test]
+    fn test_advanced_query_encoding() {
+        let query = GallerySearchQuery::new()
+            .query("ignored")
+            .all("cute cats")
+            .exactly("100% fluffy")
+            .not("dogs&birds")
+            .file_type(SearchFileType::Anigif)
+            .size(SearchImageSize::Lrg);
+
+        assert!(query.is_advanced());
+        assert_eq!(
+            query.to_query_string(),
+            "q_all=cute+cats&q_exactly=100%25+fluffy&q_not=dogs%26birds&q_type=anigif&q_size_px=lrg"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::model::custom_gallery::CustomGallery;
+use crate::model::{custom_gallery::CustomGalleryItem, topic::Topic};
 
 /// Gallery tag entry
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -9,35 +9,39 @@ use crate::model::custom_gallery::CustomGallery;
 #[serde(deny_unknown_fields)]
 pub struct Tag {
     /// The name of the tag
-    name: String,
+    pub name: String,
     /// Displayed tag name
-    display_name: String,
+    pub display_name: String,
     /// Followers count
-    followers: u64,
+    pub followers: u64,
     /// Total items with this tag
-    total_items: u64,
+    pub total_items: u64,
     /// Whether the user is following this tag
-    following: bool,
+    pub following: bool,
     /// TODO: missing from API model
-    is_whitelisted: bool,
+    pub is_whitelisted: bool,
     /// TODO: missing from API model
-    background_hash: String,
+    pub background_hash: String,
     /// TODO: missing from API model
-    thumbnail_hash: Option<String>,
+    pub thumbnail_hash: Option<String>,
     /// TODO: missing from API model
-    accent: String,
+    pub accent: Option<String>,
     /// TODO: missing from API model
-    background_is_animated: bool,
+    pub background_is_animated: bool,
     /// TODO: missing from API model
-    is_promoted: bool,
+    pub thumbnail_is_animated: Option<bool>,
+    /// TODO: missing from API model
+    pub is_promoted: bool,
     /// Tag description
-    description: Option<String>,
+    pub description: Option<String>,
     /// TODO: missing from API model
-    logo_hash: Option<String>,
+    pub logo_hash: Option<String>,
     /// TODO: missing from API model
-    logo_destination_url: Option<Url>,
+    pub logo_destination_url: Option<Url>,
     /// TODO: missing from API model
-    description_annotations: serde_json::Value,
+    pub description_annotations: serde_json::Value,
+    /// Gallery items with this tag, only available when requesting the tag gallery
+    pub items: Option<Vec<CustomGalleryItem>>,
 }
 
 /// Gallery tags
@@ -46,10 +50,27 @@ pub struct Tag {
 #[serde(deny_unknown_fields)]
 pub struct GalleryTags {
     /// Vector of tags
-    tags: Vec<Tag>,
+    pub tags: Vec<Tag>,
     /// TODO: missing from API model
-    featured: String,
-    galleries: Vec<CustomGallery>,
+    pub featured: String,
+    /// Featured gallery topics
+    pub galleries: Vec<Topic>,
+}
+
+impl GalleryTags {
+    /// Listed tags followed by the user
+    pub fn followed_tags(&self) -> impl Iterator<Item = &Tag> {
+        self.tags.iter().filter(|t| t.following)
+    }
+}
+
+/// Tags of a gallery item
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct GalleryItemTags {
+    /// Vector of tags
+    pub tags: Vec<Tag>,
 }
 
 #[cfg(test)]
@@ -4195,8 +4216,9 @@ pub mod tests {
 }"#
         };
         println!("{}", pretty_json(response)?);
-        let gallery_tags = serde_json::from_str::<Basic<GalleryTags>>(response);
+        let gallery_tags = serde_json::from_str::<Basic<GalleryTags>>(response)?;
         println!("{:#?}", gallery_tags);
+        assert_eq!(gallery_tags.result()?.followed_tags().count(), 0);
         Ok(())
     }
 }
@@ -0,0 +1,38 @@
+//! Gallery votes specification
+
+use serde::{Deserialize, Serialize};
+
+/// Vote tally of a gallery item
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct GalleryVotes {
+    /// Number of upvotes
+    pub ups: u64,
+    /// Number of downvotes
+    pub downs: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use crate::model::{basic::Basic, gallery_votes::GalleryVotes};
+
+    #[test]
+    fn test_deserialize_gallery_votes_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{"data":{"ups":1889,"downs":58},"success":true,"status":200}"#;
+
+        let data = serde_json::from_str::<Basic<GalleryVotes>>(res)?.result()?;
+
+        assert_eq!(
+            data,
+            GalleryVotes {
+                ups: 1889,
+                downs: 58
+            }
+        );
+
+        Ok(())
+    }
+}
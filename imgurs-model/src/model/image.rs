@@ -1,14 +1,45 @@
 //! Image specification
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 use time::{serde::timestamp, OffsetDateTime};
 use url::Url;
 
+use crate::model::common::{AccountID, Vote};
+
+/// Image unique identifier
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ImageID(String);
+
+impl<U> From<U> for ImageID
+where
+    U: Into<String>,
+{
+    fn from(v: U) -> Self {
+        ImageID(v.into())
+    }
+}
+
+impl fmt::Display for ImageID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 /// The base model for an image.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 pub struct Image {
+    /// The account ID of the account that uploaded it, or null.
+    pub account_id: Option<AccountID>,
+    /// The username of the account that uploaded it, or null.
+    pub account_url: Option<String>,
+    /// TODO: missing from API model
+    pub ad_type: Option<serde_json::Value>,
+    /// TODO: missing from API model
+    pub ad_url: Option<serde_json::Value>,
     /// Is the image animated
     pub animated: bool,
     /// Bandwidth consumed by the image in bytes
@@ -20,14 +51,24 @@ pub struct Image {
     pub deletehash: Option<String>,
     /// Description of the image.
     pub description: Option<String>,
+    /// TODO: missing from API model
+    pub edited: Option<serde_json::Value>,
     /// Indicates if the current user favorited the image. Defaults to false if not signed in.
     pub favorite: Option<bool>,
     /// OPTIONAL, The .gifv link. Only available if the image is animated and type is 'image/gif'.
     pub gifv: Option<Url>,
+    /// TODO: missing from API model
+    pub has_sound: Option<bool>,
     /// The height of the image in pixels
     pub height: u64,
     /// The ID for the image
-    pub id: String,
+    pub id: ImageID,
+    /// True if the image has been submitted to the gallery, false if otherwise.
+    pub in_gallery: Option<bool>,
+    /// Indicates if the image is in the most viral gallery or not.
+    pub in_most_viral: Option<bool>,
+    /// TODO: missing from API model
+    pub is_ad: Option<bool>,
     /// True if the image has been submitted to the gallery, false if otherwise.
     pub is_gallery: Option<bool>,
     /// The direct link to the the image. (Note: if fetching an animated GIF that was over 20MB in original size, a .gif thumbnail will be returned)
@@ -50,12 +91,14 @@ pub struct Image {
     pub section: Option<String>,
     /// The size of the image in bytes
     pub size: u64,
+    /// TODO: missing from API model
+    pub tags: Option<Vec<serde_json::Value>>,
     /// The title of the image.
     pub title: Option<String>,
     /// The number of image views
     pub views: u64,
     /// The current user's vote on the album. null if not signed in or if the user hasn't voted on it.
-    pub vote: Option<String>,
+    pub vote: Option<Vote>,
     /// The width of the image in pixels
     pub width: u64,
 }
@@ -66,6 +109,7 @@ mod test {
 
     use crate::model::basic::Basic;
     use crate::model::gallery_image::GalleryImage;
+    use crate::model::image::Image;
 
     #[test]
     fn test_deserialize_gallery_image_local() -> Result<(), Box<dyn Error>> {
@@ -103,4 +147,46 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_deserialize_image_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{
+            "data": {
+                "id": "orunSTu",
+                "title": null,
+                "description": null,
+                "datetime": 1495556889,
+                "type": "image/gif",
+                "animated": false,
+                "width": 1,
+                "height": 1,
+                "size": 42,
+                "views": 0,
+                "bandwidth": 0,
+                "vote": null,
+                "favorite": false,
+                "nsfw": null,
+                "section": null,
+                "account_url": null,
+                "account_id": 0,
+                "is_ad": false,
+                "in_most_viral": false,
+                "tags": [],
+                "ad_type": 0,
+                "ad_url": "",
+                "in_gallery": false,
+                "deletehash": "x70po4w7BVvSUzZ",
+                "name": "",
+                "link": "http://i.imgur.com/orunSTu.gif"
+            },
+            "success": true,
+            "status": 200
+        }"#;
+
+        let data = serde_json::from_str::<Basic<Image>>(res)?;
+
+        println!("{:#?}", data);
+
+        Ok(())
+    }
 }
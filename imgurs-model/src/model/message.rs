@@ -1,5 +1,5 @@
 //! Message specification
-use crate::model::common::AccountID;
+use crate::model::{common::AccountID, conversation::ConversationID};
 use serde::{Deserialize, Serialize};
 use time::{serde::timestamp, OffsetDateTime};
 
@@ -9,18 +9,63 @@ use time::{serde::timestamp, OffsetDateTime};
 #[serde(deny_unknown_fields)]
 pub struct Message {
     /// The ID for the message
-    id: u64,
+    pub id: u64,
     /// Account username of person sending the message
-    from: String,
+    pub from: String,
     /// The account ID of the person receiving the message
-    account_id: AccountID,
+    pub account_id: AccountID,
     /// The account ID of the person who sent the message
-    sender_id: AccountID,
+    pub sender_id: AccountID,
     /// Text of the message
-    body: String,
+    pub body: String,
     /// ID for the overall conversation
-    conversation_id: u64,
+    pub conversation_id: ConversationID,
     /// Time message was sent, epoch time
     #[serde(with = "timestamp")]
-    datetime: OffsetDateTime,
+    pub datetime: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use crate::model::basic::Basic;
+    use crate::model::conversation::ConversationEntry;
+
+    #[test]
+    fn test_deserialize_conversation_messages_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{
+            "data": {
+                "id": 188129,
+                "with_account": "jasdev",
+                "with_account_id": 3698510,
+                "last_message_preview": "hi",
+                "message_count": 1,
+                "datetime": 1406927327,
+                "messages": [
+                    {
+                        "id": 11247,
+                        "from": "jasdev",
+                        "account_id": 3698510,
+                        "sender_id": 3698510,
+                        "body": "hi",
+                        "conversation_id": 188129,
+                        "datetime": 1406927327
+                    }
+                ],
+                "done": true,
+                "page": 1
+            },
+            "success": true,
+            "status": 200
+        }"#;
+
+        let data = serde_json::from_str::<Basic<ConversationEntry>>(res)?.result()?;
+        let messages = data.messages.expect("Messages should be present");
+
+        assert_eq!(messages[0].from, "jasdev");
+        assert_eq!(messages[0].conversation_id, data.id);
+
+        Ok(())
+    }
 }
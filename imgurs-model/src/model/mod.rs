@@ -4,15 +4,20 @@ pub mod album;
 pub mod basic;
 pub mod comment;
 pub mod conversation;
+pub mod credits;
 pub mod custom_gallery;
 pub mod gallery_album;
 pub mod gallery_image;
 pub mod gallery_profile;
+pub mod gallery_search;
 pub mod gallery_tags;
+pub mod gallery_votes;
 
 pub mod image;
 
 pub mod message;
+pub mod notification;
+pub mod topic;
 
 pub mod authorization;
 pub mod common;
@@ -0,0 +1,129 @@
+//! Notification specification
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{comment::Comment, common::AccountID, conversation::ConversationEntry};
+
+/// Unique identifier of a notification
+pub type NotificationID = u64;
+
+/// The base model for a notification.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct Notification<T> {
+    /// The ID for the notification
+    pub id: NotificationID,
+    /// The Account ID for the notification
+    pub account_id: AccountID,
+    /// Has the user viewed the image yet?
+    pub viewed: bool,
+    /// This can be any other model, currently only using comments and conversation metadata.
+    pub content: T,
+}
+
+/// Notification of a reply to a comment of the user
+pub type ReplyNotification = Notification<Comment>;
+
+/// Notification of a new message for the user
+pub type MessageNotification = Notification<ConversationEntry>;
+
+/// Content of a notification of unknown kind
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(untagged)]
+pub enum NotificationContent {
+    /// Comment reply
+    Reply(Comment),
+    /// Conversation message
+    Message(ConversationEntry),
+}
+
+/// Notifications of the user, split by kind
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct Notifications {
+    /// Comment reply notifications
+    pub replies: Vec<ReplyNotification>,
+    /// Message notifications
+    pub messages: Vec<MessageNotification>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use crate::model::{
+        basic::Basic,
+        notification::{Notification, NotificationContent, Notifications},
+    };
+
+    #[test]
+    fn test_deserialize_notifications_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{
+            "data": {
+                "replies": [
+                    {
+                        "id": 76878181,
+                        "account_id": 384077,
+                        "viewed": false,
+                        "content": {"id":1938489503,"image_id":"CRprgNU","comment":"Don’t be coy, what is he making?","author":"TheBeigePhillip","author_id":102217801,"on_album":true,"album_cover":"w2gwdJp","ups":93,"downs":2,"points":91,"datetime":1599059372,"parent_id":0,"deleted":false,"vote":null,"platform":"iphone","has_admin_badge":false,"children":[]}
+                    }
+                ],
+                "messages": [
+                    {
+                        "id": 76878178,
+                        "account_id": 384077,
+                        "viewed": false,
+                        "content": {
+                            "id": 188129,
+                            "with_account": "jasdev",
+                            "with_account_id": 3698510,
+                            "last_message_preview": "Hello!",
+                            "message_count": 6,
+                            "datetime": 1407277466
+                        }
+                    }
+                ]
+            },
+            "success": true,
+            "status": 200
+        }"#;
+
+        let data = serde_json::from_str::<Basic<Notifications>>(res)?.result()?;
+
+        assert_eq!(data.replies[0].content.id, 1938489503);
+        assert_eq!(data.messages[0].content.with_account, "jasdev");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_notification_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{
+            "data": {
+                "id": 76878178,
+                "account_id": 384077,
+                "viewed": true,
+                "content": {
+                    "id": 188129,
+                    "with_account": "jasdev",
+                    "with_account_id": 3698510,
+                    "last_message_preview": "Hello!",
+                    "message_count": 6,
+                    "datetime": 1407277466
+                }
+            },
+            "success": true,
+            "status": 200
+        }"#;
+
+        let data =
+            serde_json::from_str::<Basic<Notification<NotificationContent>>>(res)?.result()?;
+
+        assert!(matches!(data.content, NotificationContent::Message(_)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+//! Gallery topic specification
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::custom_gallery::CustomGalleryItem;
+
+/// Unique identifier of a topic
+pub type TopicID = u64;
+
+/// The base model for a gallery topic.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// #[serde(deny_unknown_fields)]
+pub struct Topic {
+    /// ID of the topic.
+    pub id: TopicID,
+    /// Topic title.
+    pub name: String,
+    /// Description of the topic.
+    pub description: String,
+    /// CSS class used on web to format the topic's name.
+    pub css: Option<String>,
+    /// Whether the topic is ephemeral, i.e. it will disappear after some time.
+    pub ephemeral: Option<bool>,
+    /// TODO: missing from API model
+    #[serde(rename = "topPost")]
+    pub top_post: Option<CustomGalleryItem>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use crate::model::{basic::Basic, topic::Topic};
+
+    #[test]
+    fn test_deserialize_topics_local() -> Result<(), Box<dyn Error>> {
+        let res = r#"{
+            "data": [
+                {
+                    "id": 29,
+                    "name": "No Topic",
+                    "description": "Everything else",
+                    "css": null,
+                    "ephemeral": false
+                }
+            ],
+            "success": true,
+            "status": 200
+        }"#;
+
+        let data = serde_json::from_str::<Basic<Vec<Topic>>>(res)?.result()?;
+
+        assert_eq!(data[0].id, 29);
+
+        Ok(())
+    }
+}